@@ -0,0 +1,70 @@
+//! Benchmark of barrel pattern matching with many patterns
+//!
+//! Compares the linear scan over all compiled patterns with the lookup through
+//! `PatternIndex`, for configs with one pattern per barrel module as generated for
+//! monorepos. Run with `cargo bench --bench pattern_index`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use swc_plugin_barrel_files::{CompiledPattern, PatternIndex};
+
+const LOOKUPS: usize = 100_000;
+
+/// Creates one literal pattern per barrel module and one wildcard pattern per package
+fn patterns(count: usize) -> Vec<CompiledPattern> {
+    (0..count)
+        .map(|index| match index % 2 {
+            0 => format!(
+                "/cwd/packages/pkg{}/src/modules/module{}/index.ts",
+                index / 10,
+                index
+            ),
+            _ => format!("/cwd/packages/pkg{}/src/features/*/index.ts", index),
+        })
+        .map(|pattern| CompiledPattern::new(&pattern).unwrap())
+        .collect()
+}
+
+/// Creates the looked up paths, half of them matched by a pattern
+fn paths(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|index| match index % 4 {
+            0 => format!(
+                "/cwd/packages/pkg{}/src/modules/module{}/index.ts",
+                index / 10,
+                index
+            ),
+            1 => format!("/cwd/packages/pkg{}/src/features/user/index.ts", index),
+            2 => format!("/cwd/packages/pkg{}/src/utils/format.ts", index / 10),
+            _ => "/cwd/node_modules/react/index.js".to_string(),
+        })
+        .collect()
+}
+
+/// Measures the average time of a lookup
+fn measure(paths: &[String], lookup: impl Fn(&str) -> Option<usize>) -> Duration {
+    let start = Instant::now();
+
+    for index in 0..LOOKUPS {
+        black_box(lookup(black_box(&paths[index % paths.len()])));
+    }
+
+    start.elapsed() / LOOKUPS as u32
+}
+
+fn main() {
+    println!("{:>8}  {:>12}  {:>12}", "patterns", "linear scan", "index");
+
+    for count in [10, 25, 50, 100, 1_000, 5_000] {
+        let patterns = patterns(count);
+        let paths = paths(count);
+        let index = PatternIndex::new(patterns.clone());
+
+        let linear = measure(&paths, |path| {
+            patterns.iter().position(|pattern| pattern.matches(path))
+        });
+        let indexed = measure(&paths, |path| index.find(path));
+
+        println!("{:>8}  {:>12?}  {:>12?}", count, linear, indexed);
+    }
+}
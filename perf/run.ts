@@ -43,7 +43,45 @@ function generateSwcConfig(plugin: unknown): void {
     fs.writeFileSync(SWC_CONFIG_PATH, JSON.stringify(config, null, 4), "utf8");
 }
 
+/**
+ * Lists barrel module names generated by `perf/generate-fixtures.ts`
+ */
+function getModuleNames(): string[] {
+    return fs
+        .readdirSync(FIXTURES_DIR, { withFileTypes: true })
+        .filter((entry) => entry.isDirectory() && fs.existsSync(path.join(FIXTURES_DIR, entry.name, "index.ts")))
+        .map((entry) => entry.name)
+        .sort();
+}
+
+/**
+ * Builds a config with one literal pattern and one alias per barrel module,
+ * which is what generated monorepo configs with hundreds of entries look like
+ */
+function getManyPatternsConfig(wasmPath: string) {
+    const modules = getModuleNames();
+    const barrelPath = (moduleName: string) =>
+        path.resolve(FIXTURES_DIR, moduleName, "index.ts").replace(/\\/g, "/");
+
+    return [
+        wasmPath,
+        {
+            patterns: modules.map(barrelPath),
+            aliases: modules.map((moduleName) => ({
+                pattern: `#src/${moduleName}`,
+                paths: [barrelPath(moduleName)],
+            })),
+            unsupported_import_mode: "error",
+            invalid_barrel_mode: "error",
+        },
+    ];
+}
+
 function getBarrelFilesConfig(wasmPath: string) {
+    if (manyPatterns) {
+        return getManyPatternsConfig(wasmPath);
+    }
+
     return [
         wasmPath,
         {
@@ -139,11 +177,13 @@ async function runBenchmark(configs: Config[]) {
 }
 
 const args = process.argv.slice(2);
+const manyPatterns = args.includes("--many-patterns");
 
 if (args.includes("--help") || args.includes("-h")) {
     console.log("Usage: tsx perf/run.ts [options]");
     console.log("Options:");
     console.log("  --base <path>            Run benchmark against custom base build (provide WASM path)");
+    console.log("  --many-patterns          Use one pattern and one alias per barrel module instead of wildcards");
     console.log("  --help, -h               Show this help message");
     process.exit(0);
 }
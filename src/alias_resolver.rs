@@ -4,10 +4,14 @@
 //! and finding corresponding barrel files. It handles pattern matching and path resolution
 //! to support dynamic imports and re-exports in the barrel files system.
 
-use crate::config::Alias;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::config::{Alias, MissingBarrelMode};
+use crate::errors::{BarrelPluginError, ImportIssues};
 use crate::path_resolver::PathResolver;
-use crate::paths::{file_exists, path_join};
-use crate::pattern_matcher::{apply_components_to_template, CompiledPattern};
+use crate::paths::{dirname, file_exists, path_join, resolve_source_file};
+use crate::pattern_matcher::{apply_components_to_template, CompiledPattern, PatternIndex};
 
 /// Pre-compiled path alias
 #[derive(Clone)]
@@ -16,13 +20,165 @@ struct CompiledAlias {
     alias: Alias,
     /// Pre-compiled pattern for matching
     compiled_pattern: CompiledPattern,
+    /// Pre-compiled path templates, in the order of `alias.paths`, for converting module
+    /// paths back into aliased import paths
+    templates: Vec<Result<CompiledTemplate, BarrelPluginError>>,
+}
+
+impl CompiledAlias {
+    /// Expands an import path into the virtual paths of the alias templates the way
+    /// TypeScript `paths` and bundlers do, where a single wildcard also matches `/`
+    ///
+    /// # Returns
+    ///
+    /// The expanded templates in order, `None` if the alias does not match the import path
+    fn expand(&self, import_path: &str) -> Option<Vec<String>> {
+        let pattern = &self.compiled_pattern;
+
+        let components = match pattern.wildcard_count {
+            0 if import_path == pattern.parts[0] => HashMap::new(),
+            1 => {
+                let (prefix, suffix) = (&pattern.parts[0], &pattern.parts[1]);
+                if import_path.len() <= prefix.len() + suffix.len()
+                    || !import_path.starts_with(prefix.as_str())
+                    || !import_path.ends_with(suffix.as_str())
+                {
+                    return None;
+                }
+
+                let value = &import_path[prefix.len()..import_path.len() - suffix.len()];
+                HashMap::from([("p0".to_string(), value.to_string())])
+            }
+            _ if pattern.matches(import_path) => pattern.extract_components(import_path),
+            _ => return None,
+        };
+
+        Some(
+            self.templates
+                .iter()
+                .flatten()
+                .map(|template| apply_components_to_template(&template.template, &components))
+                .collect(),
+        )
+    }
+}
+
+/// Path template of an alias resolved to a virtual path
+#[derive(Clone)]
+struct CompiledTemplate {
+    /// Virtual path template, e.g. `/cwd/src/modules/*/index.ts`
+    template: String,
+    /// Pre-compiled template for matching module paths
+    compiled_template: CompiledPattern,
+    /// Pre-compiled directory of a template ending with a file name, e.g. `/cwd/src/modules/*`
+    compiled_dir: Option<CompiledPattern>,
+}
+
+impl CompiledTemplate {
+    /// Compiles a path template of an alias
+    fn new(path_template: &str, path_resolver: &PathResolver) -> Result<Self, BarrelPluginError> {
+        let template = path_resolver.resolve_path(path_template);
+        let template = path_resolver.to_virtual_path(&template)?;
+
+        let file_name = template.rsplit('/').next().unwrap_or_default();
+        let compiled_dir = if file_name.contains('*') {
+            None
+        } else {
+            Some(compile_template(&dirname(&template))?)
+        };
+
+        Ok(Self {
+            compiled_template: compile_template(&template)?,
+            compiled_dir,
+            template,
+        })
+    }
+
+    /// Lists the import paths through an alias that may point to a module
+    ///
+    /// # Arguments
+    ///
+    /// * `alias` - The alias the template belongs to
+    /// * `module_path` - The virtual path of the module
+    /// * `module_file` - The file the module path resolves to
+    ///
+    /// # Returns
+    ///
+    /// The candidate import paths, which still need to be resolved back to the module
+    fn alias_candidates(
+        &self,
+        alias: &CompiledAlias,
+        module_path: &str,
+        module_file: &str,
+    ) -> Vec<String> {
+        let alias_pattern = &alias.alias.pattern;
+        let wildcard_count = alias.compiled_pattern.wildcard_count;
+        let mut candidates = Vec::new();
+
+        if self.compiled_template.wildcard_count != wildcard_count {
+            return candidates;
+        }
+
+        if let Some(path) = [module_path, module_file]
+            .into_iter()
+            .find(|path| self.compiled_template.matches(path))
+        {
+            let components = self.compiled_template.extract_components(path);
+            candidates.push(apply_components_to_template(alias_pattern, &components));
+        }
+
+        if let Some(compiled_dir) = &self.compiled_dir {
+            if let Some(prefix_len) = compiled_dir.match_prefix(module_path) {
+                let components = compiled_dir.extract_components(&module_path[..prefix_len]);
+                let alias_path = apply_components_to_template(alias_pattern, &components);
+                candidates.push(format!("{}{}", alias_path, &module_path[prefix_len..]));
+            }
+        }
+
+        if wildcard_count == 1 && self.template.ends_with("/*") && alias_pattern.ends_with('*') {
+            let template_prefix = self.compiled_template.literal_prefix();
+            if let Some(rest) = module_path.strip_prefix(template_prefix) {
+                if !rest.is_empty() {
+                    let alias_prefix = &alias_pattern[..alias_pattern.len() - 1];
+                    candidates.push(format!("{}{}", alias_prefix, rest));
+                }
+            }
+        }
+
+        candidates
+    }
+}
+
+/// Compares alias patterns by specificity, the more specific pattern goes first
+///
+/// Follows the TypeScript `paths` precedence: patterns without wildcards win over patterns
+/// with wildcards, then the pattern with the longest literal prefix wins.
+fn compare_specificity(a: &CompiledPattern, b: &CompiledPattern) -> Ordering {
+    let a_exact = a.wildcard_count == 0;
+    let b_exact = b.wildcard_count == 0;
+
+    b_exact
+        .cmp(&a_exact)
+        .then_with(|| b.literal_prefix().len().cmp(&a.literal_prefix().len()))
+}
+
+/// Compiles a path template of an alias
+fn compile_template(template: &str) -> Result<CompiledPattern, BarrelPluginError> {
+    CompiledPattern::new(template).map_err(|reason| BarrelPluginError::InvalidPattern {
+        pattern: template.to_string(),
+        reason,
+    })
 }
 
 /// Resolver for import aliases
 pub struct AliasResolver {
-    /// Pre-compiled aliases sorted by specificity (fewer wildcards first)
+    /// Pre-compiled aliases sorted by specificity (exact patterns first, then longest
+    /// literal prefix first, then declaration order)
     compiled_aliases: Vec<CompiledAlias>,
 
+    /// Alias patterns indexed by their literal prefix, in the order of `compiled_aliases`
+    pattern_index: PatternIndex,
+
     /// Resolver for file paths
     path_resolver: PathResolver,
 }
@@ -34,7 +190,7 @@ impl AliasResolver {
         path_resolver: &PathResolver,
         cwd: &str,
         source_file: &str,
-    ) -> Result<Self, String> {
+    ) -> Result<Self, BarrelPluginError> {
         let mut compiled_aliases = Vec::new();
 
         // Filter aliases by context and patterns
@@ -51,23 +207,41 @@ impl AliasResolver {
             };
 
             if should_include {
-                let compiled_pattern = CompiledPattern::new(&alias.pattern).map_err(|e| {
-                    format!("Failed to compile alias pattern '{}': {}", alias.pattern, e)
+                let compiled_pattern = CompiledPattern::new(&alias.pattern).map_err(|reason| {
+                    BarrelPluginError::InvalidPattern {
+                        pattern: alias.pattern.clone(),
+                        reason,
+                    }
                 })?;
 
+                let templates = alias
+                    .paths
+                    .iter()
+                    .map(|path_template| CompiledTemplate::new(path_template, path_resolver))
+                    .collect();
+
                 compiled_aliases.push(CompiledAlias {
                     alias: alias.clone(),
                     compiled_pattern,
+                    templates,
                 });
             }
         }
 
-        // Pre-sort aliases by specificity (fewer wildcards = more specific)
+        // Pre-sort aliases by specificity, the stable sort keeps declaration order for ties
         compiled_aliases
-            .sort_by_key(|compiled_alias| compiled_alias.compiled_pattern.wildcard_count);
+            .sort_by(|a, b| compare_specificity(&a.compiled_pattern, &b.compiled_pattern));
+
+        let pattern_index = PatternIndex::new(
+            compiled_aliases
+                .iter()
+                .map(|compiled_alias| compiled_alias.compiled_pattern.clone())
+                .collect(),
+        );
 
         Ok(Self {
             compiled_aliases,
+            pattern_index,
             path_resolver: path_resolver.clone(),
         })
     }
@@ -75,20 +249,32 @@ impl AliasResolver {
     /// Resolves an import path using configured aliases
     ///
     /// This function attempts to match the import path against configured alias patterns
-    /// and resolve it to an actual file path. It tries each potential path template
-    /// until it finds one that exists in the filesystem.
+    /// and resolve it to an actual file path. It tries each matching alias in precedence order
+    /// and each of its path templates until it finds one that exists in the filesystem.
     ///
     /// # Arguments
     ///
     /// * `import_path` - The import path to resolve
+    /// * `missing_barrel_mode` - How to handle imports for which no matching alias resolves
+    ///   to an existing file
+    /// * `issues` - Collects the warnings and the reason for skipping the import
     ///
     /// # Returns
     ///
     /// * `Ok(Some(String))` - The resolved file path if found
     /// * `Ok(None)` - If no matching alias was found or no matching file exists
-    /// * `Err(String)` - If there was an error during resolution
-    pub fn resolve(&self, import_path: &str) -> Result<Option<String>, String> {
-        if let Some(compiled_alias) = self.match_pattern(import_path) {
+    /// * `Err(BarrelPluginError)` - If there was an error during resolution
+    pub fn resolve(
+        &self,
+        import_path: &str,
+        missing_barrel_mode: MissingBarrelMode,
+        issues: &mut ImportIssues,
+    ) -> Result<Option<String>, BarrelPluginError> {
+        let mut has_matches = false;
+
+        for compiled_alias in self.matching_aliases(import_path) {
+            has_matches = true;
+
             let components = compiled_alias
                 .compiled_pattern
                 .extract_components(import_path);
@@ -102,16 +288,131 @@ impl AliasResolver {
                     return Ok(Some(path));
                 }
             }
+        }
+
+        if !has_matches {
+            return Ok(None);
+        }
+
+        let error = BarrelPluginError::AliasNotResolved {
+            import_path: import_path.to_string(),
+        };
+
+        match missing_barrel_mode {
+            MissingBarrelMode::Error => Err(error),
+            MissingBarrelMode::Warn => {
+                issues.skip(error, true);
+                Ok(None)
+            }
+            MissingBarrelMode::Off => {
+                issues.skip(error, false);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Converts a module path back into an aliased import path
+    ///
+    /// This is the reverse of [`AliasResolver::resolve`]. Aliases are tried in precedence order,
+    /// and for each path template the module path is matched against:
+    ///
+    /// 1. The whole template, e.g. `src/shared/ui/index.ts` for `@ui`, also matching the
+    ///    file the module path resolves to
+    /// 2. The directory of a template ending with a file name, e.g. `src/modules/*` for
+    ///    `@modules/*`, in which case the rest of the module path is appended to the alias
+    /// 3. The literal prefix of a template ending with a wildcard, e.g. `src/` for `@/*`
+    ///    mapped to `src/*`, in which case the rest of the module path replaces the wildcard
+    ///
+    /// A candidate is only used if it resolves back to the module through the same alias,
+    /// the way TypeScript and bundlers resolve aliases. For example,
+    /// `@modules/user/models/User` is rejected for `@modules/*` mapped to
+    /// `src/modules/*/index.ts`, as it resolves to `src/modules/user/models/User/index.ts`.
+    ///
+    /// # Arguments
+    ///
+    /// * `module_path` - The virtual path of the module
+    ///
+    /// # Returns
+    ///
+    /// The aliased import path if any alias resolves to the module, `None` otherwise
+    pub fn to_alias(&self, module_path: &str) -> Result<Option<String>, BarrelPluginError> {
+        let Some(module_file) = resolve_source_file(module_path) else {
+            return Ok(None);
+        };
 
-            return Err(format!(
-                "E_BARREL_FILE_NOT_FOUND: Could not resolve barrel file for import alias {}",
-                import_path,
-            ));
+        for compiled_alias in &self.compiled_aliases {
+            for template in &compiled_alias.templates {
+                let template = template.as_ref().map_err(Clone::clone)?;
+
+                let alias_path = template
+                    .alias_candidates(compiled_alias, module_path, &module_file)
+                    .into_iter()
+                    .find(|alias_path| {
+                        compiled_alias
+                            .expand(alias_path)
+                            .and_then(|paths| {
+                                paths.iter().find_map(|path| resolve_source_file(path))
+                            })
+                            .as_ref()
+                            == Some(&module_file)
+                    });
+
+                if alias_path.is_some() {
+                    return Ok(alias_path);
+                }
+            }
         }
 
         Ok(None)
     }
 
+    /// Finds an alias that matches the import path as specifically as the selected one
+    ///
+    /// # Arguments
+    ///
+    /// * `import_path` - The import path to check
+    ///
+    /// # Returns
+    ///
+    /// The patterns of the selected alias and of the competing alias if the import is ambiguous,
+    /// `None` otherwise
+    pub fn find_ambiguity(&self, import_path: &str) -> Option<(&str, &str)> {
+        let mut matches = self.matching_aliases(import_path);
+        let selected = matches.next()?;
+
+        matches
+            .take_while(|candidate| {
+                compare_specificity(&selected.compiled_pattern, &candidate.compiled_pattern)
+                    == Ordering::Equal
+            })
+            .find(|candidate| candidate.alias.pattern != selected.alias.pattern)
+            .map(|candidate| {
+                (
+                    selected.alias.pattern.as_str(),
+                    candidate.alias.pattern.as_str(),
+                )
+            })
+    }
+
+    /// Finds all aliases matching an import path, in precedence order
+    ///
+    /// # Arguments
+    ///
+    /// * `import_path` - The import path to match
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the matching compiled aliases
+    fn matching_aliases<'a>(
+        &'a self,
+        import_path: &str,
+    ) -> impl Iterator<Item = &'a CompiledAlias> + 'a {
+        self.pattern_index
+            .find_all(import_path)
+            .into_iter()
+            .map(|index| &self.compiled_aliases[index])
+    }
+
     /// Matches an import path against the configured patterns using pre-compiled patterns
     ///
     /// # Arguments
@@ -121,14 +422,9 @@ impl AliasResolver {
     /// # Returns
     ///
     /// The matching compiled alias if found, `None` otherwise
+    #[cfg(test)]
     fn match_pattern(&self, import_path: &str) -> Option<&CompiledAlias> {
-        if self.compiled_aliases.is_empty() {
-            return None;
-        }
-
-        self.compiled_aliases
-            .iter()
-            .find(|compiled_alias| compiled_alias.compiled_pattern.matches(import_path))
+        self.matching_aliases(import_path).next()
     }
 }
 
@@ -176,6 +472,251 @@ mod tests {
         assert!(matched.is_none());
     }
 
+    #[test]
+    fn test_precedence_by_specificity() {
+        let alias = |pattern: &str| Alias {
+            pattern: pattern.to_string(),
+            paths: vec![format!("src/{}/index.ts", pattern)],
+            context: None,
+        };
+
+        let config = Some(vec![
+            alias("@app/*"),
+            alias("@app/ui/*"),
+            alias("@app/*/testing"),
+            alias("@app/ui/button"),
+            alias("*"),
+        ]);
+        let cwd = "/".to_string();
+        let source_file = "/some/file".to_string();
+        let path_resolver = PathResolver::new(&None, &cwd);
+        let resolver = AliasResolver::new(&config, &path_resolver, &cwd, &source_file).unwrap();
+
+        let patterns: Vec<&str> = resolver
+            .compiled_aliases
+            .iter()
+            .map(|a| a.alias.pattern.as_str())
+            .collect();
+        assert_eq!(
+            patterns,
+            vec![
+                "@app/ui/button",
+                "@app/ui/*",
+                "@app/*",
+                "@app/*/testing",
+                "*"
+            ]
+        );
+
+        // Exact match wins over wildcards
+        let matched = resolver.match_pattern("@app/ui/button").unwrap();
+        assert_eq!(matched.alias.pattern, "@app/ui/button");
+
+        // Longest literal prefix wins regardless of declaration order
+        let matched = resolver.match_pattern("@app/ui/input").unwrap();
+        assert_eq!(matched.alias.pattern, "@app/ui/*");
+
+        let matched = resolver.match_pattern("@app/user").unwrap();
+        assert_eq!(matched.alias.pattern, "@app/*");
+
+        let matched = resolver.match_pattern("react").unwrap();
+        assert_eq!(matched.alias.pattern, "*");
+    }
+
+    #[test]
+    fn test_find_ambiguity() {
+        let alias = |pattern: &str| Alias {
+            pattern: pattern.to_string(),
+            paths: vec!["src/index.ts".to_string()],
+            context: None,
+        };
+
+        let config = Some(vec![
+            alias("@app-*"),
+            alias("@app-*-ui"),
+            alias("@app-ui-*"),
+            alias("@lib/*"),
+            alias("@lib/*"),
+        ]);
+        let cwd = "/".to_string();
+        let source_file = "/some/file".to_string();
+        let path_resolver = PathResolver::new(&None, &cwd);
+        let resolver = AliasResolver::new(&config, &path_resolver, &cwd, &source_file).unwrap();
+
+        // Same literal prefix, declaration order decides
+        assert_eq!(
+            resolver.find_ambiguity("@app-user-ui"),
+            Some(("@app-*", "@app-*-ui"))
+        );
+        assert_eq!(
+            resolver
+                .match_pattern("@app-user-ui")
+                .unwrap()
+                .alias
+                .pattern,
+            "@app-*"
+        );
+
+        // Longer literal prefix is not ambiguous
+        assert_eq!(resolver.find_ambiguity("@app-ui-button"), None);
+
+        // The same pattern declared twice is not ambiguous
+        assert_eq!(resolver.find_ambiguity("@lib/utils"), None);
+
+        // No matching aliases
+        assert_eq!(resolver.find_ambiguity("react"), None);
+    }
+
+    #[test]
+    fn test_missing_barrel_mode() {
+        let config = Some(vec![
+            Alias {
+                pattern: "#features/*".to_string(),
+                paths: vec!["src/features/*/index.ts".to_string()],
+                context: None,
+            },
+            Alias {
+                pattern: "#*".to_string(),
+                paths: vec!["src/*/index.ts".to_string()],
+                context: None,
+            },
+        ]);
+        let cwd = "/cwd".to_string();
+        let source_file = "/cwd/src/components/Button.tsx".to_string();
+        let path_resolver = PathResolver::new(&None, &cwd);
+        let resolver = AliasResolver::new(&config, &path_resolver, &cwd, &source_file).unwrap();
+
+        let missing_error = BarrelPluginError::AliasNotResolved {
+            import_path: "#features/missing".to_string(),
+        };
+
+        let mut issues = ImportIssues::default();
+        assert_eq!(
+            resolver
+                .resolve("#features/missing", MissingBarrelMode::Error, &mut issues)
+                .unwrap_err()
+                .to_string(),
+            "E_BARREL_FILE_NOT_FOUND: Could not resolve barrel file for import alias #features/missing"
+        );
+        assert_eq!(issues, ImportIssues::default());
+
+        let mut issues = ImportIssues::default();
+        assert_eq!(
+            resolver.resolve("#features/missing", MissingBarrelMode::Warn, &mut issues),
+            Ok(None)
+        );
+        assert_eq!(issues.warnings, vec![missing_error.clone().into()]);
+        assert_eq!(issues.skip_reason, Some(missing_error.clone()));
+
+        let mut issues = ImportIssues::default();
+        assert_eq!(
+            resolver.resolve("#features/missing", MissingBarrelMode::Off, &mut issues),
+            Ok(None)
+        );
+        assert!(issues.warnings.is_empty());
+        assert_eq!(issues.skip_reason, Some(missing_error));
+
+        // Imports without matching aliases are not affected by the mode
+        let mut issues = ImportIssues::default();
+        assert_eq!(
+            resolver.resolve("react/jsx-runtime", MissingBarrelMode::Error, &mut issues),
+            Ok(None)
+        );
+        assert_eq!(issues, ImportIssues::default());
+    }
+
+    #[test]
+    fn test_to_alias() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-to-alias-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        for file in [
+            "src/modules/user/index.ts",
+            "src/modules/user/models/User.ts",
+            "src/shared/ui/index.ts",
+            "src/shared/ui/Button.tsx",
+            "src/pages/about/Page.tsx",
+            "lib/utils.ts",
+        ] {
+            let file = dir.join(file);
+            std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+            std::fs::write(file, "").unwrap();
+        }
+        let cwd = dir.to_string_lossy().to_string();
+        let path_resolver = PathResolver::new(&None, &cwd).with_root(&cwd);
+
+        let modules_alias = Alias {
+            pattern: "@modules/*".to_string(),
+            paths: vec!["src/modules/*/index.ts".to_string()],
+            context: None,
+        };
+        let config = Some(vec![
+            modules_alias.clone(),
+            Alias {
+                pattern: "@/*".to_string(),
+                paths: vec!["src/*".to_string()],
+                context: None,
+            },
+            Alias {
+                pattern: "@ui".to_string(),
+                paths: vec!["src/shared/ui/index.ts".to_string()],
+                context: None,
+            },
+        ]);
+        let source_file = format!("{}/src/pages/home.ts", cwd);
+        let resolver = AliasResolver::new(&config, &path_resolver, &cwd, &source_file).unwrap();
+        let to_alias = |module_path: &str| resolver.to_alias(&format!("{}/{}", cwd, module_path));
+
+        // Directory of a barrel file
+        assert_eq!(
+            to_alias("src/modules/user"),
+            Ok(Some("@modules/user".to_string()))
+        );
+
+        // Exact template match
+        assert_eq!(
+            to_alias("src/shared/ui/index.ts"),
+            Ok(Some("@ui".to_string()))
+        );
+
+        // Modules inside the directory of a barrel file only resolve through aliases ending
+        // with a wildcard, as `@modules/user/models/User` and `@ui/Button` resolve to other
+        // files or not at all
+        assert_eq!(
+            to_alias("src/modules/user/models/User"),
+            Ok(Some("@/modules/user/models/User".to_string()))
+        );
+        assert_eq!(
+            to_alias("src/shared/ui/Button"),
+            Ok(Some("@/shared/ui/Button".to_string()))
+        );
+        assert_eq!(
+            to_alias("src/pages/about/Page"),
+            Ok(Some("@/pages/about/Page".to_string()))
+        );
+
+        // Modules outside of any alias and missing modules
+        assert_eq!(to_alias("lib/utils"), Ok(None));
+        assert_eq!(to_alias("src/modules/user/models/Missing"), Ok(None));
+
+        // Without an alias resolving to the module, relative paths are used
+        let resolver = AliasResolver::new(
+            &Some(vec![modules_alias]),
+            &path_resolver,
+            &cwd,
+            &source_file,
+        )
+        .unwrap();
+        assert_eq!(
+            resolver.to_alias(&format!("{}/src/modules/user/models/User", cwd)),
+            Ok(None)
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_context_filtering() {
         // Create aliases with different context configurations
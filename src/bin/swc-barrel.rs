@@ -0,0 +1,366 @@
+//! Command line interface for the barrel files plugin
+//!
+//! Lists, inspects and validates the barrel files matched by a plugin configuration, and
+//! rewrites barrel imports in source files on disk.
+
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+use swc_plugin_barrel_files::{
+    analyze_barrel_files, rewrite_source, validate_barrel_files, Config,
+};
+
+const USAGE: &str = "Usage: swc-barrel <command> --config <file> [--cwd <dir>] [options] [paths...]
+
+Commands:
+  list       List the barrel files matched by the patterns
+  exports    Print the re-exports of each barrel file
+  validate   Report every unsupported item, duplicate export and missing module in the
+             barrel files
+  rewrite    Rewrite barrel imports in the given files and directories in place
+
+Options:
+  --config <file>  JSON file with the plugin configuration
+  --cwd <dir>      Directory the configuration is relative to (default: current directory)
+  --dry-run        Only print the files that would be rewritten";
+
+/// Extensions of the source files rewritten in directories
+const SOURCE_EXTENSIONS: [&str; 8] = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Command to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    List,
+    Exports,
+    Validate,
+    Rewrite,
+}
+
+/// Parsed command line arguments
+#[derive(Debug, PartialEq)]
+struct Options {
+    command: Command,
+    config: String,
+    cwd: Option<String>,
+    dry_run: bool,
+    paths: Vec<String>,
+}
+
+/// Parses the command line arguments, without the program name
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut args = args.into_iter();
+
+    let command = match args.next().as_deref() {
+        Some("list") => Command::List,
+        Some("exports") => Command::Exports,
+        Some("validate") => Command::Validate,
+        Some("rewrite") => Command::Rewrite,
+        Some(command) => return Err(format!("Unknown command '{}'", command)),
+        None => return Err("Missing command".into()),
+    };
+
+    let mut config = None;
+    let mut cwd = None;
+    let mut dry_run = false;
+    let mut paths = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config = Some(args.next().ok_or("Missing value for --config")?),
+            "--cwd" => cwd = Some(args.next().ok_or("Missing value for --cwd")?),
+            "--dry-run" => dry_run = true,
+            _ if arg.starts_with("--") => return Err(format!("Unknown option '{}'", arg)),
+            _ => paths.push(arg),
+        }
+    }
+
+    let config = config.ok_or("Missing --config option")?;
+
+    if command == Command::Rewrite && paths.is_empty() {
+        return Err("Missing paths to rewrite".into());
+    }
+
+    Ok(Options {
+        command,
+        config,
+        cwd,
+        dry_run,
+        paths,
+    })
+}
+
+/// Lists the source files in a directory and its subdirectories, skipping `node_modules`
+/// and hidden directories
+fn collect_source_files(path: &Path, files: &mut Vec<String>) {
+    if path.is_file() {
+        files.push(path.to_string_lossy().to_string());
+        return;
+    }
+
+    let Ok(entries) = fs::read_dir(path) else {
+        return;
+    };
+
+    let mut entries: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+    entries.sort();
+
+    for entry in entries {
+        let name = entry
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+
+        if entry.is_dir() {
+            if name != "node_modules" && !name.starts_with('.') {
+                collect_source_files(&entry, files);
+            }
+        } else if !name.ends_with(".d.ts")
+            && name
+                .rsplit_once('.')
+                .is_some_and(|(_, extension)| SOURCE_EXTENSIONS.contains(&extension))
+        {
+            files.push(entry.to_string_lossy().to_string());
+        }
+    }
+}
+
+/// Runs a command
+///
+/// # Returns
+///
+/// `true` if the command succeeded, `false` if invalid barrel files or imports were found
+fn run(options: &Options) -> Result<bool, String> {
+    let current_dir = std::env::current_dir().map_err(|e| e.to_string())?;
+    let cwd = current_dir.join(options.cwd.as_deref().unwrap_or("."));
+    let cwd = fs::canonicalize(&cwd)
+        .map_err(|e| format!("Failed to resolve {}: {}", cwd.display(), e))?
+        .to_string_lossy()
+        .to_string();
+
+    let config_path = current_dir.join(&options.config);
+    let config = fs::read_to_string(&config_path)
+        .map_err(|e| format!("Failed to read {}: {}", config_path.display(), e))?;
+    let config: Config = serde_json::from_str(&config).map_err(|e| {
+        format!(
+            "E_INVALID_CONFIG: Error parsing {}: {}",
+            config_path.display(),
+            e
+        )
+    })?;
+
+    match options.command {
+        Command::List => {
+            for barrel_file in analyze_barrel_files(&config, &cwd)
+                .map_err(|e| e.to_string())?
+                .keys()
+            {
+                println!("{}", barrel_file);
+            }
+
+            Ok(true)
+        }
+        Command::Exports => {
+            for (barrel_file, analysis) in
+                analyze_barrel_files(&config, &cwd).map_err(|e| e.to_string())?
+            {
+                println!("{}", barrel_file);
+
+                match analysis {
+                    Ok(re_exports) => {
+                        for re_export in re_exports {
+                            let type_only = if re_export.is_type_only {
+                                " (type)"
+                            } else {
+                                ""
+                            };
+                            let original_name =
+                                if re_export.original_name != re_export.exported_name {
+                                    format!(" ({})", re_export.original_name)
+                                } else {
+                                    String::new()
+                                };
+
+                            println!(
+                                "  {}{} -> {}{}",
+                                re_export.exported_name,
+                                type_only,
+                                re_export.source_path,
+                                original_name
+                            );
+                        }
+                    }
+                    Err(error) => println!("  {}", error),
+                }
+            }
+
+            Ok(true)
+        }
+        Command::Validate => {
+            let barrels = validate_barrel_files(&config, &cwd).map_err(|e| e.to_string())?;
+            let mut invalid = 0;
+            let mut violation_count = 0;
+
+            for (barrel_file, validation) in &barrels {
+                match validation {
+                    Ok(violations) if violations.is_empty() => {}
+                    Ok(violations) => {
+                        invalid += 1;
+                        violation_count += violations.len();
+
+                        for violation in violations {
+                            let position = violation
+                                .location
+                                .as_ref()
+                                .map(|location| format!(":{}:{}", location.line, location.column))
+                                .unwrap_or_default();
+
+                            eprintln!(
+                                "{}{}: {} [{}]",
+                                barrel_file,
+                                position,
+                                violation.error,
+                                violation.error.kind()
+                            );
+                        }
+                    }
+                    Err(error) => {
+                        invalid += 1;
+                        violation_count += 1;
+                        eprintln!("{}: {}", barrel_file, error);
+
+                        if let Some(label) = error.label() {
+                            eprintln!("  > {}", label);
+                        }
+
+                        for note in error.notes() {
+                            eprintln!("  > {}", note);
+                        }
+                    }
+                }
+            }
+
+            println!(
+                "Checked {} barrel files, {} invalid, {} violations",
+                barrels.len(),
+                invalid,
+                violation_count
+            );
+
+            Ok(invalid == 0)
+        }
+        Command::Rewrite => {
+            let mut files = Vec::new();
+            for path in &options.paths {
+                collect_source_files(&Path::new(&cwd).join(path), &mut files);
+            }
+
+            let mut failed = 0;
+
+            for file in files {
+                let source = fs::read_to_string(&file)
+                    .map_err(|e| format!("Failed to read {}: {}", file, e))?;
+
+                match rewrite_source(&config, &cwd, &file, &source) {
+                    Ok(Some(_)) if options.dry_run => println!("Would rewrite {}", file),
+                    Ok(Some(output)) => {
+                        fs::write(&file, output)
+                            .map_err(|e| format!("Failed to write {}: {}", file, e))?;
+                        println!("Rewrote {}", file);
+                    }
+                    Ok(None) => {}
+                    Err(error) => {
+                        failed += 1;
+                        eprintln!("{}: {}", file, error);
+
+                        if let Some(label) = error.label() {
+                            eprintln!("  > {}", label);
+                        }
+                    }
+                }
+            }
+
+            Ok(failed == 0)
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n\n{}", error, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("{}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        assert_eq!(
+            parse_args(args(&[
+                "rewrite",
+                "--config",
+                "barrel.json",
+                "src",
+                "--dry-run"
+            ])),
+            Ok(Options {
+                command: Command::Rewrite,
+                config: "barrel.json".into(),
+                cwd: None,
+                dry_run: true,
+                paths: vec!["src".into()],
+            })
+        );
+        assert_eq!(
+            parse_args(args(&["list", "--cwd", "app", "--config", "barrel.json"])),
+            Ok(Options {
+                command: Command::List,
+                config: "barrel.json".into(),
+                cwd: Some("app".into()),
+                dry_run: false,
+                paths: vec![],
+            })
+        );
+
+        assert_eq!(parse_args(args(&[])), Err("Missing command".into()));
+        assert_eq!(
+            parse_args(args(&["build"])),
+            Err("Unknown command 'build'".into())
+        );
+        assert_eq!(
+            parse_args(args(&["list"])),
+            Err("Missing --config option".into())
+        );
+        assert_eq!(
+            parse_args(args(&["list", "--config"])),
+            Err("Missing value for --config".into())
+        );
+        assert_eq!(
+            parse_args(args(&["list", "--config", "barrel.json", "--fix"])),
+            Err("Unknown option '--fix'".into())
+        );
+        assert_eq!(
+            parse_args(args(&["rewrite", "--config", "barrel.json"])),
+            Err("Missing paths to rewrite".into())
+        );
+    }
+}
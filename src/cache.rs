@@ -0,0 +1,359 @@
+//! Cache module for the barrel files plugin
+//!
+//! This module caches the analysis of barrel files. Entries are keyed by the path of the
+//! barrel file and stamped with its metadata, so edits to a barrel file invalidate its
+//! entry. Valid analyses can be persisted to the cache directory set in the configuration,
+//! to be reused by other processes and later builds.
+
+use crate::errors::BarrelPluginError;
+use crate::re_export::ReExport;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::UNIX_EPOCH;
+
+/// Result of analyzing a barrel file
+///
+/// Invalid barrel files are stored as the error, so that the invalid barrel mode
+/// of the pattern matching the barrel can be applied on each lookup.
+pub type BarrelAnalysis = Result<Vec<ReExport>, BarrelPluginError>;
+
+/// Names exported by a module targeted by re-exports, `None` if they cannot be determined
+/// statically
+pub type TargetExports = Option<HashSet<String>>;
+
+/// Version of the plugin, persisted entries of other versions are ignored
+const CACHE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Cache for analyzed barrel files shared by all transformed files of the process
+static BARREL_CACHE: Lazy<Mutex<BarrelCache>> = Lazy::new(|| Mutex::new(BarrelCache::default()));
+
+/// Number of temporary files written by the process, to name them uniquely
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// Exports of the modules verified with `verify_targets: "exports"`, stamped like the
+/// barrel analyses but only kept in memory
+static TARGET_EXPORTS_CACHE: Lazy<Mutex<HashMap<String, (FileStamp, TargetExports)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Metadata of a file identifying its content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileStamp {
+    /// Size of the file in bytes
+    size: u64,
+
+    /// Modification time in nanoseconds since the Unix epoch
+    modified: Option<u64>,
+
+    /// Hash of the content, for platforms not providing the modification time
+    content_hash: Option<u64>,
+}
+
+impl FileStamp {
+    /// Reads the stamp of a file
+    ///
+    /// # Returns
+    ///
+    /// The stamp of the file, `None` if its metadata cannot be read
+    pub fn of(file_path: &str) -> Option<Self> {
+        let metadata = fs::metadata(file_path).ok()?;
+
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_nanos() as u64);
+
+        let content_hash = match modified {
+            Some(_) => None,
+            None => Some(fnv1a_hash(&fs::read(file_path).ok()?)),
+        };
+
+        Some(Self {
+            size: metadata.len(),
+            modified,
+            content_hash,
+        })
+    }
+}
+
+/// Analysis of a barrel file stamped with the metadata of the analyzed file
+#[derive(Debug, Clone)]
+struct CacheEntry {
+    stamp: FileStamp,
+    analysis: BarrelAnalysis,
+}
+
+/// Analysis of a valid barrel file as persisted to the cache directory
+#[derive(Debug, Serialize, Deserialize)]
+struct PersistedEntry {
+    version: String,
+    path: String,
+    stamp: FileStamp,
+    re_exports: Vec<ReExport>,
+}
+
+/// Cache for analyzed barrel files
+///
+/// Entries are kept in memory for all visitors, while the directory to persist them to is
+/// passed by each visitor from its configuration.
+#[derive(Debug, Default)]
+pub struct BarrelCache {
+    /// Entries in memory by barrel file path
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl BarrelCache {
+    /// Looks up the analysis of a barrel file
+    ///
+    /// Entries missing in memory are loaded from the cache directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the barrel file
+    /// * `stamp` - The current stamp of the barrel file
+    /// * `dir` - The directory analyses are persisted to, if any
+    ///
+    /// # Returns
+    ///
+    /// The analysis if it was made for the same stamp, `None` otherwise
+    pub fn get(
+        &mut self,
+        file_path: &str,
+        stamp: &FileStamp,
+        dir: Option<&str>,
+    ) -> Option<BarrelAnalysis> {
+        if let Some(entry) = self.entries.get(file_path) {
+            if entry.stamp == *stamp {
+                return Some(entry.analysis.clone());
+            }
+        }
+
+        let persisted = load(dir?, file_path)?;
+        if persisted.stamp != *stamp {
+            return None;
+        }
+
+        let analysis = Ok(persisted.re_exports);
+        self.entries.insert(
+            file_path.to_string(),
+            CacheEntry {
+                stamp: *stamp,
+                analysis: analysis.clone(),
+            },
+        );
+
+        Some(analysis)
+    }
+
+    /// Stores the analysis of a barrel file
+    ///
+    /// Valid analyses are also persisted to the cache directory. Failing to persist is
+    /// ignored, as the barrel file can still be analyzed again.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_path` - The path to the barrel file
+    /// * `stamp` - The stamp of the barrel file before it was analyzed
+    /// * `analysis` - The analysis of the barrel file
+    /// * `dir` - The directory to persist valid analyses to, if any
+    pub fn insert(
+        &mut self,
+        file_path: &str,
+        stamp: FileStamp,
+        analysis: BarrelAnalysis,
+        dir: Option<&str>,
+    ) {
+        if let (Ok(re_exports), Some(dir)) = (&analysis, dir) {
+            let _ = persist(dir, file_path, stamp, re_exports);
+        }
+
+        self.entries
+            .insert(file_path.to_string(), CacheEntry { stamp, analysis });
+    }
+}
+
+/// Path of the file persisting the analysis of a barrel file in a cache directory
+fn entry_path(dir: &str, file_path: &str) -> String {
+    format!("{}/{:016x}.json", dir, fnv1a_hash(file_path.as_bytes()))
+}
+
+/// Reads the persisted analysis of a barrel file
+fn load(dir: &str, file_path: &str) -> Option<PersistedEntry> {
+    let content = fs::read_to_string(entry_path(dir, file_path)).ok()?;
+    let persisted: PersistedEntry = serde_json::from_str(&content).ok()?;
+
+    (persisted.version == CACHE_VERSION && persisted.path == file_path).then_some(persisted)
+}
+
+/// Writes the analysis of a barrel file to a cache directory
+///
+/// The entry is written to a temporary file first and then renamed, so processes
+/// reading the cache concurrently never see a partially written entry. The temporary file
+/// is named after the process and a counter, so concurrent writers never share it.
+fn persist(
+    dir: &str,
+    file_path: &str,
+    stamp: FileStamp,
+    re_exports: &[ReExport],
+) -> io::Result<()> {
+    let entry_path = entry_path(dir, file_path);
+
+    let persisted = PersistedEntry {
+        version: CACHE_VERSION.to_string(),
+        path: file_path.to_string(),
+        stamp,
+        re_exports: re_exports.to_vec(),
+    };
+    let content = serde_json::to_string(&persisted)?;
+
+    let temp_path = format!(
+        "{}.{}.{}.tmp",
+        entry_path,
+        std::process::id(),
+        TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+    );
+
+    fs::create_dir_all(Path::new(dir))?;
+    fs::write(&temp_path, content)?;
+    fs::rename(&temp_path, &entry_path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })
+}
+
+/// Looks up the analysis of a barrel file in the shared cache
+///
+/// See [`BarrelCache::get`].
+pub fn get_analysis(
+    file_path: &str,
+    stamp: &FileStamp,
+    dir: Option<&str>,
+) -> Option<BarrelAnalysis> {
+    BARREL_CACHE.lock().ok()?.get(file_path, stamp, dir)
+}
+
+/// Stores the analysis of a barrel file in the shared cache
+///
+/// See [`BarrelCache::insert`].
+pub fn insert_analysis(
+    file_path: &str,
+    stamp: FileStamp,
+    analysis: BarrelAnalysis,
+    dir: Option<&str>,
+) {
+    if let Ok(mut cache) = BARREL_CACHE.lock() {
+        cache.insert(file_path, stamp, analysis, dir);
+    }
+}
+
+/// Looks up the exports of a re-export target
+///
+/// # Returns
+///
+/// The exports if they were listed for the same stamp, `None` otherwise
+pub fn get_target_exports(file_path: &str, stamp: &FileStamp) -> Option<TargetExports> {
+    let cache = TARGET_EXPORTS_CACHE.lock().ok()?;
+    let (cached_stamp, exports) = cache.get(file_path)?;
+
+    (cached_stamp == stamp).then(|| exports.clone())
+}
+
+/// Stores the exports of a re-export target
+pub fn insert_target_exports(file_path: &str, stamp: FileStamp, exports: TargetExports) {
+    if let Ok(mut cache) = TARGET_EXPORTS_CACHE.lock() {
+        cache.insert(file_path.to_string(), (stamp, exports));
+    }
+}
+
+/// Hashes bytes with the 64-bit FNV-1a function, which is stable across builds
+fn fnv1a_hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn re_export(name: &str) -> ReExport {
+        ReExport {
+            exported_name: name.to_string(),
+            source_path: format!("./{}", name),
+            original_name: name.to_string(),
+            is_default: false,
+            is_type_only: false,
+        }
+    }
+
+    #[test]
+    fn test_barrel_cache() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-cache-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let barrel = dir.join("index.ts").to_string_lossy().to_string();
+        let cache_dir = dir.join("cache").to_string_lossy().to_string();
+
+        fs::write(&barrel, "export { Button } from './Button';").unwrap();
+        let stamp = FileStamp::of(&barrel).unwrap();
+
+        let cache_dir = Some(cache_dir.as_str());
+
+        let mut cache = BarrelCache::default();
+        assert_eq!(cache.get(&barrel, &stamp, cache_dir), None);
+        cache.insert(&barrel, stamp, Ok(vec![re_export("Button")]), cache_dir);
+        assert_eq!(
+            cache.get(&barrel, &stamp, cache_dir),
+            Some(Ok(vec![re_export("Button")]))
+        );
+
+        // Persisted for other processes using the same cache directory
+        let mut other_cache = BarrelCache::default();
+        assert_eq!(
+            other_cache.get(&barrel, &stamp, cache_dir),
+            Some(Ok(vec![re_export("Button")]))
+        );
+        assert_eq!(BarrelCache::default().get(&barrel, &stamp, None), None);
+        let temp_files = fs::read_dir(dir.join("cache"))
+            .unwrap()
+            .flatten()
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+            .count();
+        assert_eq!(temp_files, 0);
+
+        // Invalidated by edits to the barrel file
+        fs::write(&barrel, "export { Button, Card } from './Button';").unwrap();
+        let new_stamp = FileStamp::of(&barrel).unwrap();
+        assert_ne!(new_stamp, stamp);
+        assert_eq!(cache.get(&barrel, &new_stamp, cache_dir), None);
+        assert_eq!(other_cache.get(&barrel, &new_stamp, cache_dir), None);
+
+        // Invalid barrel files are only cached in memory
+        let error = BarrelPluginError::NoReExports {
+            barrel_file: barrel.clone(),
+        };
+        cache.insert(&barrel, new_stamp, Err(error.clone()), cache_dir);
+        assert_eq!(cache.get(&barrel, &new_stamp, cache_dir), Some(Err(error)));
+        assert_eq!(
+            BarrelCache::default().get(&barrel, &new_stamp, cache_dir),
+            None
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fnv1a_hash() {
+        assert_eq!(fnv1a_hash(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a_hash(b"a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(fnv1a_hash(b"index.ts"), fnv1a_hash(b"index.tsx"));
+    }
+}
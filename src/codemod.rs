@@ -0,0 +1,287 @@
+//! Codemod module for the barrel files plugin
+//!
+//! This module rewrites barrel imports in source files, so a codebase can be migrated away
+//! from barrel files once instead of transforming the imports on every build. Only the
+//! rewritten import declarations are replaced in the source code; the rest of the file,
+//! including its formatting and comments, is kept as is.
+
+use crate::config::{Config, TransformMode};
+use crate::errors::BarrelPluginError;
+use crate::import_transformer::{format_import_clause, source_location, syntax_for};
+use crate::paths::path_join;
+use crate::visitor::BarrelTransformVisitor;
+use swc_core::common::comments::SingleThreadedComments;
+use swc_core::common::errors::{ColorConfig, Handler, HANDLER};
+use swc_core::common::sync::Lrc;
+use swc_core::common::{BytePos, FileName, SourceMap, Spanned};
+use swc_core::ecma::ast::{ImportDecl, ModuleDecl, ModuleItem};
+use swc_core::ecma::parser::parse_file_as_module;
+use swc_core::ecma::visit::VisitMutWith;
+
+/// Pairs the import declarations of a module with the declarations replacing them
+///
+/// The replacements of an import keep its span, so they are found by comparing the items
+/// before and after the transform.
+fn replaced_imports<'a>(
+    original: &'a [ModuleItem],
+    transformed: &'a [ModuleItem],
+) -> Vec<(&'a ImportDecl, Vec<&'a ImportDecl>)> {
+    let imports = |items: &'a [ModuleItem]| {
+        items.iter().filter_map(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Some(import),
+            _ => None,
+        })
+    };
+
+    imports(original)
+        .filter_map(|import| {
+            let replacements: Vec<&ImportDecl> = imports(transformed)
+                .filter(|replacement| replacement.span == import.span)
+                .collect();
+
+            (replacements != [import]).then_some((import, replacements))
+        })
+        .collect()
+}
+
+/// Formats the declarations replacing an import in the style of the original import
+///
+/// The declarations are put on separate lines with the indentation of the original import,
+/// using the quotes of its module path and keeping the code after it as written, i.e. the
+/// import attributes and the trailing semicolon.
+///
+/// # Arguments
+///
+/// * `source_literal` - The module path of the original import as written, with its quotes
+/// * `tail` - The code of the original import after the module path
+/// * `indentation` - The indentation of the original import
+/// * `replacements` - The declarations replacing the original import
+fn format_replacements(
+    source_literal: &str,
+    tail: &str,
+    indentation: &str,
+    replacements: &[&ImportDecl],
+) -> String {
+    let quote = if source_literal.starts_with('\'') {
+        "'"
+    } else {
+        "\""
+    };
+
+    replacements
+        .iter()
+        .map(|replacement| {
+            format!(
+                "import {} from {}{}{}{}",
+                format_import_clause(replacement),
+                quote,
+                replacement.src.value,
+                quote,
+                tail
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(&format!("\n{}", indentation))
+}
+
+/// Rewrites the barrel imports of a source file
+///
+/// `merge_imports` is not applied, so other import declarations are left unchanged.
+///
+/// # Arguments
+///
+/// * `config` - The plugin configuration
+/// * `cwd` - The current working directory
+/// * `filename` - The path to the source file, absolute or relative to `cwd`
+/// * `source` - The source code of the file
+///
+/// # Returns
+///
+/// The rewritten source code, `None` if no import was rewritten, `Err` if the file cannot
+/// be parsed or an import cannot be rewritten. Diagnostics are printed to stderr.
+pub fn rewrite_source(
+    config: &Config,
+    cwd: &str,
+    filename: &str,
+    source: &str,
+) -> Result<Option<String>, BarrelPluginError> {
+    let path = path_join(cwd, filename);
+    let cm: Lrc<SourceMap> = Default::default();
+    let fm = cm.new_source_file(
+        FileName::Real(path.clone().into()).into(),
+        source.to_string(),
+    );
+    let comments = SingleThreadedComments::default();
+
+    let mut module = parse_file_as_module(
+        &fm,
+        syntax_for(filename),
+        Default::default(),
+        Some(&comments),
+        &mut vec![],
+    )
+    .map_err(|e| BarrelPluginError::FileParse {
+        path: path.clone(),
+        location: source_location(&cm, &path, e.span()),
+        reason: e.kind().msg().to_string(),
+    })?;
+    let original = module.body.clone();
+
+    let config = Config {
+        mode: TransformMode::Transform,
+        merge_imports: None,
+        ..config.clone()
+    };
+
+    let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
+    HANDLER.set(&handler, || {
+        let visitor = BarrelTransformVisitor::with_fs_root(
+            &config,
+            cwd.to_string(),
+            cwd,
+            filename.to_string(),
+            Some(comments.clone()),
+        )?;
+
+        if let Some(mut visitor) = visitor {
+            module.visit_mut_with(&mut visitor);
+        }
+
+        Ok(())
+    })?;
+
+    if handler.has_errors() {
+        return Err(BarrelPluginError::RewriteFailed { path });
+    }
+
+    let replaced = replaced_imports(&original, &module.body);
+    if replaced.is_empty() {
+        return Ok(None);
+    }
+
+    let mut output = String::with_capacity(source.len());
+    let mut position = 0;
+
+    let offset = |pos: BytePos| (pos - fm.start_pos).0 as usize;
+
+    for (import, replacements) in replaced {
+        let lo = offset(import.span.lo);
+        let hi = offset(import.span.hi);
+        let src_lo = offset(import.src.span.lo);
+        let src_hi = offset(import.src.span.hi);
+
+        let line_start = source[..lo].rfind('\n').map_or(0, |index| index + 1);
+        let indentation: String = source[line_start..lo]
+            .chars()
+            .take_while(|c| c.is_whitespace())
+            .collect();
+
+        output.push_str(&source[position..lo]);
+        output.push_str(&format_replacements(
+            &source[src_lo..src_hi],
+            &source[src_hi..hi],
+            &indentation,
+            &replacements,
+        ));
+        position = hi;
+    }
+
+    output.push_str(&source[position..]);
+
+    Ok(Some(output))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_rewrite_source() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-codemod-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src/features/user")).unwrap();
+        fs::write(
+            dir.join("src/features/user/index.ts"),
+            r#"
+                export { UserCard } from "./ui/UserCard";
+                export { selectUser, type User } from "./model/user";
+            "#,
+        )
+        .unwrap();
+        let cwd = dir.to_string_lossy().to_string();
+
+        let config: Config = serde_json::from_str(
+            r##"{
+                "aliases": [{ "pattern": "#features/*", "paths": ["src/features/*/index.ts"] }],
+                "patterns": ["src/features/*/index.ts"]
+            }"##,
+        )
+        .unwrap();
+
+        let source = r##"// Page
+import { useState } from 'react';
+import { UserCard, selectUser, type User } from '#features/user';
+// barrel-files-disable-next-line
+import { UserCard as Card } from "#features/user"
+
+export const page = [UserCard, Card, selectUser];
+"##;
+
+        assert_eq!(
+            rewrite_source(&config, &cwd, "src/pages/page.tsx", source).unwrap(),
+            Some(
+                r##"// Page
+import { useState } from 'react';
+import { UserCard } from '../features/user/ui/UserCard';
+import { selectUser, type User } from '../features/user/model/user';
+// barrel-files-disable-next-line
+import { UserCard as Card } from "#features/user"
+
+export const page = [UserCard, Card, selectUser];
+"##
+                .to_string()
+            )
+        );
+
+        // Import attributes are kept after each module path
+        assert_eq!(
+            rewrite_source(
+                &config,
+                &cwd,
+                "src/pages/attributes.ts",
+                "  import { UserCard, selectUser } from \"#features/user\" with { type: \"module\" };\n"
+            )
+            .unwrap(),
+            Some(
+                "  import { UserCard } from \"../features/user/ui/UserCard\" with { type: \"module\" };\n  import { selectUser } from \"../features/user/model/user\" with { type: \"module\" };\n"
+                    .to_string()
+            )
+        );
+
+        // Files without barrel imports are left unchanged
+        assert_eq!(
+            rewrite_source(
+                &config,
+                &cwd,
+                "src/pages/other.ts",
+                "import { useState } from \"react\""
+            )
+            .unwrap(),
+            None
+        );
+
+        // Files failing to parse are reported
+        let error = rewrite_source(&config, &cwd, "src/pages/broken.ts", "import {").unwrap_err();
+        assert_eq!(error.code(), "E_FILE_PARSE");
+        assert_eq!(
+            error.label(),
+            Some(format!("syntax error at {}/src/pages/broken.ts:1:8", cwd))
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,245 @@
+//! Compiled configuration module for the barrel files plugin
+//!
+//! This module compiles the parts of the configuration that do not depend on the
+//! transformed file, like the barrel file patterns and the `include` and `exclude` globs.
+//! The compiled configurations are shared by the visitors of all files of the process, so
+//! the patterns are compiled once per configuration instead of once per file.
+
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+
+use crate::config::{BarrelOptions, Config};
+use crate::errors::BarrelPluginError;
+use crate::glob::Glob;
+use crate::path_resolver::PathResolver;
+use crate::paths::path_join;
+use crate::pattern_matcher::{CompiledPattern, PatternIndex};
+
+/// Maximum number of compiled configurations kept in memory
+///
+/// A process usually transforms all files with the same configuration, so only a few are
+/// kept and the oldest one is dropped when the limit is reached.
+const MAX_COMPILED_CONFIGS: usize = 8;
+
+/// Compiled configuration with the configuration, current working directory and filesystem
+/// root it was compiled for
+type CompiledConfigEntry = ((Config, String, String), Arc<CompiledConfig>);
+
+/// Compiled configurations shared by all transformed files of the process
+static COMPILED_CONFIGS: Lazy<Mutex<Vec<CompiledConfigEntry>>> =
+    Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Settings compiled from a configuration, independent of the transformed file
+pub struct CompiledConfig {
+    /// Pre-compiled patterns for barrel files, indexed by their literal prefix
+    pub patterns: PatternIndex,
+
+    /// Settings for barrel files matched by each pattern, in the order of `patterns`
+    pub pattern_options: Vec<BarrelOptions>,
+
+    /// File names of the barrel files matched by patterns, e.g. `index.ts`, used to find the
+    /// barrel files bypassed by deep imports
+    pub barrel_file_names: Vec<String>,
+
+    /// Globs for the source files to transform, all files if not set
+    include: Option<Vec<Glob>>,
+
+    /// Globs for the source files to leave unchanged, with the pattern they were compiled from
+    exclude: Vec<(String, Glob)>,
+}
+
+impl CompiledConfig {
+    /// Returns the compiled configuration, compiling it on first use
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The plugin configuration
+    /// * `cwd` - The current working directory
+    /// * `fs_root` - The directory at which the current working directory is mounted
+    /// * `path_resolver` - The resolver for file paths, created for `cwd` and `fs_root`
+    ///
+    /// # Returns
+    ///
+    /// The compiled configuration, `Err` if a pattern or glob cannot be compiled
+    pub fn get(
+        config: &Config,
+        cwd: &str,
+        fs_root: &str,
+        path_resolver: &PathResolver,
+    ) -> Result<Arc<Self>, BarrelPluginError> {
+        let cached = COMPILED_CONFIGS.lock().ok().and_then(|compiled_configs| {
+            compiled_configs
+                .iter()
+                .find(|((cached_config, cached_cwd, cached_fs_root), _)| {
+                    cached_cwd == cwd && cached_fs_root == fs_root && cached_config == config
+                })
+                .map(|(_, compiled_config)| compiled_config.clone())
+        });
+        if let Some(compiled_config) = cached {
+            return Ok(compiled_config);
+        }
+
+        let compiled_config = Arc::new(Self::new(config, cwd, path_resolver)?);
+
+        if let Ok(mut compiled_configs) = COMPILED_CONFIGS.lock() {
+            if compiled_configs.len() >= MAX_COMPILED_CONFIGS {
+                compiled_configs.remove(0);
+            }
+            compiled_configs.push((
+                (config.clone(), cwd.to_string(), fs_root.to_string()),
+                compiled_config.clone(),
+            ));
+        }
+
+        Ok(compiled_config)
+    }
+
+    /// Compiles the patterns and globs of a configuration
+    fn new(
+        config: &Config,
+        cwd: &str,
+        path_resolver: &PathResolver,
+    ) -> Result<Self, BarrelPluginError> {
+        let patterns = compile_patterns(config, cwd, path_resolver)?;
+
+        let compile_glob = |pattern: &String| -> Result<Glob, BarrelPluginError> {
+            let virtual_path = path_resolver.to_virtual_path(&path_join(cwd, pattern))?;
+            Ok(Glob::new(&virtual_path))
+        };
+
+        let include = match &config.include {
+            Some(include) => Some(include.iter().map(compile_glob).collect::<Result<_, _>>()?),
+            None => None,
+        };
+
+        let exclude = config
+            .exclude
+            .iter()
+            .flatten()
+            .map(|pattern| Ok((pattern.clone(), compile_glob(pattern)?)))
+            .collect::<Result<_, BarrelPluginError>>()?;
+
+        Ok(Self {
+            patterns,
+            pattern_options: config
+                .patterns
+                .iter()
+                .map(|pattern| config.barrel_options(pattern))
+                .collect(),
+            barrel_file_names: barrel_file_names(config),
+            include,
+            exclude,
+        })
+    }
+
+    /// Checks a source file against the `include` and `exclude` globs
+    ///
+    /// # Arguments
+    ///
+    /// * `source_file` - The virtual path of the source file
+    ///
+    /// # Returns
+    ///
+    /// The reason for leaving the source file unchanged, `None` if it should be transformed
+    pub fn exclusion_reason(&self, source_file: &str) -> Option<String> {
+        if let Some(include) = &self.include {
+            if !include.iter().any(|glob| glob.matches(source_file)) {
+                return Some("not matched by include".to_string());
+            }
+        }
+
+        self.exclude
+            .iter()
+            .find(|(_, glob)| glob.matches(source_file))
+            .map(|(pattern, _)| format!("matched by exclude \"{}\"", pattern))
+    }
+}
+
+/// Compiles the barrel file patterns of a configuration into an index
+fn compile_patterns(
+    config: &Config,
+    cwd: &str,
+    path_resolver: &PathResolver,
+) -> Result<PatternIndex, BarrelPluginError> {
+    let mut compiled_patterns = Vec::new();
+
+    for pattern in &config.patterns {
+        let joined_path = path_join(cwd, &pattern.pattern);
+        let virtual_path = path_resolver.to_virtual_path(&joined_path)?;
+
+        let compiled_pattern = CompiledPattern::new(&virtual_path).map_err(|reason| {
+            BarrelPluginError::InvalidPattern {
+                pattern: virtual_path.clone(),
+                reason,
+            }
+        })?;
+
+        compiled_patterns.push(compiled_pattern);
+    }
+
+    Ok(PatternIndex::new(compiled_patterns))
+}
+
+/// Lists the file names of the barrel files matched by the patterns
+///
+/// Patterns with a wildcard in the file name, e.g. `src/*.barrel.ts`, are left out.
+fn barrel_file_names(config: &Config) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    for pattern in &config.patterns {
+        let name = pattern.pattern.rsplit('/').next().unwrap_or_default();
+
+        if !name.is_empty() && !name.contains('*') && !names.iter().any(|n| n == name) {
+            names.push(name.to_string());
+        }
+    }
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compiled_config() {
+        let config: Config = serde_json::from_str(
+            r#"{
+                "patterns": ["src/features/*/index.ts", "src/ui/*.barrel.ts"],
+                "include": ["src/**"],
+                "exclude": ["**/*.stories.tsx"]
+            }"#,
+        )
+        .unwrap();
+        let path_resolver = PathResolver::new(&None, "/project").with_root("/cwd");
+
+        let compiled_config = CompiledConfig::get(&config, "/project", "/cwd", &path_resolver)
+            .unwrap_or_else(|error| panic!("{}", error));
+        assert_eq!(compiled_config.barrel_file_names, vec!["index.ts"]);
+        assert_eq!(compiled_config.pattern_options.len(), 2);
+
+        assert_eq!(
+            compiled_config.exclusion_reason("/cwd/src/pages/home.ts"),
+            None
+        );
+        assert_eq!(
+            compiled_config.exclusion_reason("/cwd/tests/home.ts"),
+            Some("not matched by include".to_string())
+        );
+        assert_eq!(
+            compiled_config.exclusion_reason("/cwd/src/ui/Button.stories.tsx"),
+            Some("matched by exclude \"**/*.stories.tsx\"".to_string())
+        );
+
+        // The configuration is compiled once for all files
+        let cached = CompiledConfig::get(&config, "/project", "/cwd", &path_resolver).unwrap();
+        assert!(Arc::ptr_eq(&compiled_config, &cached));
+
+        let native_resolver = PathResolver::new(&None, "/project").with_root("/project");
+        let native =
+            CompiledConfig::get(&config, "/project", "/project", &native_resolver).unwrap();
+        assert!(!Arc::ptr_eq(&compiled_config, &native));
+        assert_eq!(native.exclusion_reason("/project/src/pages/home.ts"), None);
+    }
+}
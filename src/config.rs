@@ -1,3 +1,4 @@
+use serde::de::{self, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use std::collections::HashMap;
 use std::fmt;
@@ -82,11 +83,368 @@ impl<'de> Deserialize<'de> for InvalidBarrelMode {
     }
 }
 
+/// Mode for handling imports that should point to a barrel file that does not exist
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MissingBarrelMode {
+    /// Throw an error and stop compilation
+    #[default]
+    Error,
+    /// Print a warning and skip the import
+    Warn,
+    /// Silently skip the import
+    Off,
+}
+
+impl fmt::Display for MissingBarrelMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MissingBarrelMode::Error => write!(f, "error"),
+            MissingBarrelMode::Warn => write!(f, "warn"),
+            MissingBarrelMode::Off => write!(f, "off"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MissingBarrelMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "error" => Ok(MissingBarrelMode::Error),
+            "warn" => Ok(MissingBarrelMode::Warn),
+            "off" => Ok(MissingBarrelMode::Off),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid missing_barrel_mode '{}'. Valid options are: error, warn, off",
+                s
+            ))),
+        }
+    }
+}
+
+/// Mode for handling files importing the barrel file of their own directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SelfImportMode {
+    /// Report an error failing the build
+    Error,
+    /// Report a warning
+    #[default]
+    Warn,
+    /// Do not report self-imports
+    Off,
+}
+
+impl fmt::Display for SelfImportMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SelfImportMode::Error => write!(f, "error"),
+            SelfImportMode::Warn => write!(f, "warn"),
+            SelfImportMode::Off => write!(f, "off"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SelfImportMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "error" => Ok(SelfImportMode::Error),
+            "warn" => Ok(SelfImportMode::Warn),
+            "off" => Ok(SelfImportMode::Off),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid self_import_mode '{}'. Valid options are: error, warn, off",
+                s
+            ))),
+        }
+    }
+}
+
+/// Mode for handling imports of modules inside the directory of a barrel file from outside
+/// of this directory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeepImportMode {
+    /// Report an error failing the build
+    Error,
+    /// Report a warning
+    Warn,
+    /// Allow deep imports
+    #[default]
+    Off,
+}
+
+impl fmt::Display for DeepImportMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeepImportMode::Error => write!(f, "error"),
+            DeepImportMode::Warn => write!(f, "warn"),
+            DeepImportMode::Off => write!(f, "off"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DeepImportMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "error" => Ok(DeepImportMode::Error),
+            "warn" => Ok(DeepImportMode::Warn),
+            "off" => Ok(DeepImportMode::Off),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid deep_import_mode '{}'. Valid options are: error, warn, off",
+                s
+            ))),
+        }
+    }
+}
+
+/// How the modules targeted by re-exports are verified before rewriting imports to them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VerifyTargetsMode {
+    /// Trust the barrel file
+    #[default]
+    Off,
+    /// Check that the re-exported module exists
+    Exists,
+    /// Check that the re-exported module exists and exports the re-exported name
+    Exports,
+}
+
+impl fmt::Display for VerifyTargetsMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifyTargetsMode::Off => write!(f, "off"),
+            VerifyTargetsMode::Exists => write!(f, "exists"),
+            VerifyTargetsMode::Exports => write!(f, "exports"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for VerifyTargetsMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "off" => Ok(VerifyTargetsMode::Off),
+            "exists" => Ok(VerifyTargetsMode::Exists),
+            "exports" => Ok(VerifyTargetsMode::Exports),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid verify_targets '{}'. Valid options are: off, exists, exports",
+                s
+            ))),
+        }
+    }
+}
+
+/// Style of the import paths written by the transform
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputPathStyle {
+    /// Paths relative to the importing file, e.g. `../../modules/user/models/User`
+    #[default]
+    Relative,
+    /// Paths through the configured aliases, e.g. `@modules/user/models/User`, falling back
+    /// to relative paths for modules not covered by any alias
+    Alias,
+    /// Paths from the project root, e.g. `/src/modules/user/models/User`
+    AbsoluteFromRoot,
+}
+
+impl fmt::Display for OutputPathStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OutputPathStyle::Relative => write!(f, "relative"),
+            OutputPathStyle::Alias => write!(f, "alias"),
+            OutputPathStyle::AbsoluteFromRoot => write!(f, "absolute-from-root"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for OutputPathStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "relative" => Ok(OutputPathStyle::Relative),
+            "alias" => Ok(OutputPathStyle::Alias),
+            "absolute-from-root" => Ok(OutputPathStyle::AbsoluteFromRoot),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid output_path_style '{}'. Valid options are: relative, alias, absolute-from-root",
+                s
+            ))),
+        }
+    }
+}
+
+/// What the plugin does with the barrel imports it resolves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TransformMode {
+    /// Rewrite barrel imports into direct imports
+    #[default]
+    Transform,
+    /// Report the imports that would be rewritten and leave the program unchanged
+    Check,
+}
+
+impl fmt::Display for TransformMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransformMode::Transform => write!(f, "transform"),
+            TransformMode::Check => write!(f, "check"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TransformMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "transform" => Ok(TransformMode::Transform),
+            "check" => Ok(TransformMode::Check),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid mode '{}'. Valid options are: transform, check",
+                s
+            ))),
+        }
+    }
+}
+
+/// Destination of warnings and debug logs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiagnosticsOutput {
+    /// Report through SWC diagnostics, at the span of the import
+    #[default]
+    Swc,
+    /// Print warnings to stderr and debug logs to stdout
+    Console,
+}
+
+impl fmt::Display for DiagnosticsOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiagnosticsOutput::Swc => write!(f, "swc"),
+            DiagnosticsOutput::Console => write!(f, "console"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DiagnosticsOutput {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        match s.as_str() {
+            "swc" => Ok(DiagnosticsOutput::Swc),
+            "console" => Ok(DiagnosticsOutput::Console),
+            _ => Err(serde::de::Error::custom(format!(
+                "Invalid diagnostics_output '{}'. Valid options are: swc, console",
+                s
+            ))),
+        }
+    }
+}
+
+/// Extension of the import paths written by the transform
+///
+/// Only script extensions (`ts`, `tsx`, `mts`, `cts`, `js`, `jsx`, `mjs`, `cjs`) are rewritten,
+/// paths with other extensions (e.g. `.css`) are treated as paths without an extension.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum OutputExtension {
+    /// Keep the extension used in the barrel file
+    #[default]
+    Preserve,
+    /// Remove script extensions
+    Strip,
+    /// Replace script extensions with `.js` and add it to paths without an extension
+    Js,
+    /// Replace script extensions with `.mjs` and add it to paths without an extension
+    Mjs,
+    /// Replace extensions using a table from source to output extension, where the empty
+    /// key stands for paths without an extension and the empty value removes the extension
+    Mapping(HashMap<String, String>),
+}
+
+impl<'de> Deserialize<'de> for OutputExtension {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct OutputExtensionVisitor;
+
+        impl<'de> Visitor<'de> for OutputExtensionVisitor {
+            type Value = OutputExtension;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "an output extension string or an extension mapping object"
+                )
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                match value {
+                    "preserve" => Ok(OutputExtension::Preserve),
+                    "strip" => Ok(OutputExtension::Strip),
+                    "js" => Ok(OutputExtension::Js),
+                    "mjs" => Ok(OutputExtension::Mjs),
+                    _ => Err(de::Error::custom(format!(
+                        "Invalid output_extension '{}'. Valid options are: preserve, strip, js, mjs, or an extension mapping",
+                        value
+                    ))),
+                }
+            }
+
+            fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let mapping = HashMap::<String, String>::deserialize(
+                    de::value::MapAccessDeserializer::new(map),
+                )?;
+
+                // Extensions can be written with or without the leading dot
+                let mapping = mapping
+                    .into_iter()
+                    .map(|(from, to)| {
+                        (
+                            from.trim_start_matches('.').to_string(),
+                            to.trim_start_matches('.').to_string(),
+                        )
+                    })
+                    .collect();
+
+                Ok(OutputExtension::Mapping(mapping))
+            }
+        }
+
+        deserializer.deserialize_any(OutputExtensionVisitor)
+    }
+}
+
 /// Configuration for the barrel files plugin
-#[derive(Debug, Deserialize, Clone)]
+///
+/// The default configuration has no patterns, so native embedders can build one with
+/// `Config { patterns: ..., ..Default::default() }`.
+#[derive(Debug, Default, Deserialize, Clone, PartialEq)]
 pub struct Config {
     /// Patterns for barrel files
-    pub patterns: Vec<String>,
+    pub patterns: Vec<BarrelPattern>,
 
     /// Rules for resolving import aliases (optional)
     pub aliases: Option<Vec<Alias>>,
@@ -94,9 +452,23 @@ pub struct Config {
     /// Symlink mappings from external paths to internal paths (optional)
     pub symlinks: Option<HashMap<String, String>>,
 
-    /// Enables debug logging to stdout
+    /// Globs for the source files to transform, all files under cwd if not set (optional)
+    pub include: Option<Vec<String>>,
+
+    /// Globs for the source files to leave unchanged (optional)
+    pub exclude: Option<Vec<String>>,
+
+    /// Enables debug logging
     pub debug: Option<bool>,
 
+    /// Whether to rewrite barrel imports or only report them
+    #[serde(default)]
+    pub mode: TransformMode,
+
+    /// Where to report warnings and debug logs
+    #[serde(default)]
+    pub diagnostics_output: DiagnosticsOutput,
+
     /// How to handle unsupported import patterns (e.g. namespace imports)
     #[serde(default)]
     pub unsupported_import_mode: UnsupportedImportMode,
@@ -104,10 +476,176 @@ pub struct Config {
     /// How to handle invalid barrel files (files with unsupported constructs)
     #[serde(default)]
     pub invalid_barrel_mode: InvalidBarrelMode,
+
+    /// How to handle imports without an existing barrel file: aliased imports for which
+    /// no alias resolves to an existing file and local imports matched by patterns
+    #[serde(default)]
+    pub missing_barrel_mode: MissingBarrelMode,
+
+    /// How to verify the modules targeted by re-exports before rewriting imports to them
+    #[serde(default)]
+    pub verify_targets: VerifyTargetsMode,
+
+    /// How to report files importing the barrel file of their own directory, which creates
+    /// a circular dependency
+    #[serde(default)]
+    pub self_import_mode: SelfImportMode,
+
+    /// How to report imports bypassing a barrel file, i.e. imports of modules inside the
+    /// directory of a barrel file from outside of this directory
+    #[serde(default)]
+    pub deep_import_mode: DeepImportMode,
+
+    /// Style of the import paths pointing to the source modules
+    #[serde(default)]
+    pub output_path_style: OutputPathStyle,
+
+    /// Extension of the import paths pointing to the source modules
+    #[serde(default)]
+    pub output_extension: OutputExtension,
+
+    /// Merges rewritten imports with other imports of the same module
+    pub merge_imports: Option<bool>,
+
+    /// Path to a JSON Lines file, relative to the current working directory, to which a
+    /// record of every barrel import is appended
+    pub report: Option<String>,
+
+    /// Directory, relative to the current working directory, in which the analyses of
+    /// barrel files are persisted between builds (optional)
+    pub cache_dir: Option<String>,
+
+    /// Path to a JSON file, relative to the current working directory, with the precomputed
+    /// re-exports of barrel files (optional)
+    pub manifest: Option<String>,
+}
+
+impl Config {
+    /// Resolves the settings for barrel files matched by the given pattern
+    ///
+    /// Options set on the pattern take precedence over the global ones.
+    pub fn barrel_options(&self, pattern: &BarrelPattern) -> BarrelOptions {
+        BarrelOptions {
+            unsupported_import_mode: pattern
+                .unsupported_import_mode
+                .unwrap_or(self.unsupported_import_mode),
+            invalid_barrel_mode: pattern
+                .invalid_barrel_mode
+                .unwrap_or(self.invalid_barrel_mode),
+            follow_nested: pattern.follow_nested.unwrap_or_default(),
+            verify_targets: self.verify_targets,
+            output_path_style: pattern.output.unwrap_or(self.output_path_style),
+        }
+    }
+}
+
+/// Pattern for barrel files with optional per-pattern settings
+///
+/// Can be configured either as a plain string or as an object with a `pattern` field.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarrelPattern {
+    /// Pattern to match against barrel file paths
+    pub pattern: String,
+
+    /// How to handle unsupported import patterns for this group of barrels
+    pub unsupported_import_mode: Option<UnsupportedImportMode>,
+
+    /// How to handle invalid barrel files for this group of barrels
+    pub invalid_barrel_mode: Option<InvalidBarrelMode>,
+
+    /// Follow re-exports through nested barrel files down to the source module
+    pub follow_nested: Option<bool>,
+
+    /// Style of the import paths pointing to the source modules of this group of barrels
+    pub output: Option<OutputPathStyle>,
+}
+
+impl From<&str> for BarrelPattern {
+    fn from(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_string(),
+            unsupported_import_mode: None,
+            invalid_barrel_mode: None,
+            follow_nested: None,
+            output: None,
+        }
+    }
+}
+
+/// Object form of a barrel pattern
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BarrelPatternObject {
+    pattern: String,
+    unsupported_import_mode: Option<UnsupportedImportMode>,
+    invalid_barrel_mode: Option<InvalidBarrelMode>,
+    follow_nested: Option<bool>,
+    output: Option<OutputPathStyle>,
+}
+
+impl<'de> Deserialize<'de> for BarrelPattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct BarrelPatternVisitor;
+
+        impl<'de> Visitor<'de> for BarrelPatternVisitor {
+            type Value = BarrelPattern;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a pattern string or an object with a `pattern` field")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: de::Error,
+            {
+                Ok(BarrelPattern::from(value))
+            }
+
+            fn visit_map<M>(self, map: M) -> Result<Self::Value, M::Error>
+            where
+                M: MapAccess<'de>,
+            {
+                let object =
+                    BarrelPatternObject::deserialize(de::value::MapAccessDeserializer::new(map))?;
+
+                Ok(BarrelPattern {
+                    pattern: object.pattern,
+                    unsupported_import_mode: object.unsupported_import_mode,
+                    invalid_barrel_mode: object.invalid_barrel_mode,
+                    follow_nested: object.follow_nested,
+                    output: object.output,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(BarrelPatternVisitor)
+    }
+}
+
+/// Settings applied to a matched barrel file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BarrelOptions {
+    /// How to handle unsupported import patterns
+    pub unsupported_import_mode: UnsupportedImportMode,
+
+    /// How to handle invalid barrel files
+    pub invalid_barrel_mode: InvalidBarrelMode,
+
+    /// Follow re-exports through nested barrel files
+    pub follow_nested: bool,
+
+    /// How to verify the modules targeted by re-exports
+    pub verify_targets: VerifyTargetsMode,
+
+    /// Style of the import paths pointing to the source modules
+    pub output_path_style: OutputPathStyle,
 }
 
 /// Rule for resolving import aliases
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Clone, PartialEq)]
 pub struct Alias {
     /// Pattern to match against import paths.
     pub pattern: String,
@@ -150,8 +688,102 @@ mod tests {
         assert_eq!(context, &vec!["src"]);
         let patterns = config.patterns;
         assert_eq!(patterns.len(), 2);
-        assert_eq!(patterns[0], "src/entities/*/index.ts");
-        assert_eq!(patterns[1], "src/features/*/index.ts");
+        assert_eq!(patterns[0].pattern, "src/entities/*/index.ts");
+        assert_eq!(patterns[1].pattern, "src/features/*/index.ts");
+    }
+
+    #[test]
+    fn test_pattern_options_parsing() {
+        let config_json = r#"{
+            "patterns": [
+                "src/legacy/*/index.ts",
+                {
+                    "pattern": "src/modules/*/index.ts",
+                    "invalid_barrel_mode": "warn",
+                    "follow_nested": true,
+                    "output": "alias"
+                }
+            ],
+            "invalid_barrel_mode": "off",
+            "unsupported_import_mode": "warn"
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+
+        assert_eq!(config.patterns.len(), 2);
+        assert_eq!(
+            config.patterns[0],
+            BarrelPattern::from("src/legacy/*/index.ts")
+        );
+        assert_eq!(config.patterns[1].pattern, "src/modules/*/index.ts");
+        assert_eq!(
+            config.patterns[1].invalid_barrel_mode,
+            Some(InvalidBarrelMode::Warn)
+        );
+        assert_eq!(config.patterns[1].unsupported_import_mode, None);
+        assert_eq!(config.patterns[1].follow_nested, Some(true));
+        assert_eq!(config.patterns[1].output, Some(OutputPathStyle::Alias));
+
+        // Global options are used for plain string patterns
+        assert_eq!(
+            config.barrel_options(&config.patterns[0]),
+            BarrelOptions {
+                unsupported_import_mode: UnsupportedImportMode::Warn,
+                invalid_barrel_mode: InvalidBarrelMode::Off,
+                follow_nested: false,
+                verify_targets: VerifyTargetsMode::Off,
+                output_path_style: OutputPathStyle::Relative,
+            }
+        );
+
+        // Pattern options take precedence over global ones
+        assert_eq!(
+            config.barrel_options(&config.patterns[1]),
+            BarrelOptions {
+                unsupported_import_mode: UnsupportedImportMode::Warn,
+                invalid_barrel_mode: InvalidBarrelMode::Warn,
+                follow_nested: true,
+                verify_targets: VerifyTargetsMode::Off,
+                output_path_style: OutputPathStyle::Alias,
+            }
+        );
+
+        // Misspelled pattern options are rejected
+        let result: Result<Config, _> = serde_json::from_str(
+            r#"{ "patterns": [{ "pattern": "src/modules/*/index.ts", "folow_nested": true }] }"#,
+        );
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("unknown field `folow_nested`"));
+    }
+
+    #[test]
+    fn test_invalid_pattern_options() {
+        let missing_pattern = r#"{
+            "patterns": [{ "invalid_barrel_mode": "warn" }]
+        }"#;
+        let result: Result<Config, _> = serde_json::from_str(missing_pattern);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("missing field `pattern`"));
+
+        let invalid_mode = r#"{
+            "patterns": [{ "pattern": "src/*/index.ts", "invalid_barrel_mode": "invalid" }]
+        }"#;
+        let result: Result<Config, _> = serde_json::from_str(invalid_mode);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid invalid_barrel_mode"));
+
+        let invalid_type = r#"{
+            "patterns": [42]
+        }"#;
+        let result: Result<Config, _> = serde_json::from_str(invalid_type);
+        assert!(result.is_err());
     }
 
     #[test]
@@ -180,6 +812,14 @@ mod tests {
 
         assert_eq!(config.unsupported_import_mode, UnsupportedImportMode::Error);
         assert_eq!(config.invalid_barrel_mode, InvalidBarrelMode::Error);
+        assert_eq!(config.missing_barrel_mode, MissingBarrelMode::Error);
+        assert_eq!(config.output_path_style, OutputPathStyle::Relative);
+        assert_eq!(config.output_extension, OutputExtension::Preserve);
+        assert_eq!(config.diagnostics_output, DiagnosticsOutput::Swc);
+        assert_eq!(config.mode, TransformMode::Transform);
+        assert_eq!(config.verify_targets, VerifyTargetsMode::Off);
+        assert_eq!(config.self_import_mode, SelfImportMode::Warn);
+        assert_eq!(config.deep_import_mode, DeepImportMode::Off);
     }
 
     #[test]
@@ -238,5 +878,254 @@ mod tests {
         assert_eq!(InvalidBarrelMode::Error.to_string(), "error");
         assert_eq!(InvalidBarrelMode::Warn.to_string(), "warn");
         assert_eq!(InvalidBarrelMode::Off.to_string(), "off");
+
+        assert_eq!(MissingBarrelMode::Error.to_string(), "error");
+        assert_eq!(MissingBarrelMode::Warn.to_string(), "warn");
+        assert_eq!(MissingBarrelMode::Off.to_string(), "off");
+
+        assert_eq!(OutputPathStyle::Relative.to_string(), "relative");
+        assert_eq!(OutputPathStyle::Alias.to_string(), "alias");
+        assert_eq!(
+            OutputPathStyle::AbsoluteFromRoot.to_string(),
+            "absolute-from-root"
+        );
+
+        assert_eq!(DiagnosticsOutput::Swc.to_string(), "swc");
+        assert_eq!(DiagnosticsOutput::Console.to_string(), "console");
+
+        assert_eq!(TransformMode::Transform.to_string(), "transform");
+        assert_eq!(TransformMode::Check.to_string(), "check");
+
+        assert_eq!(VerifyTargetsMode::Off.to_string(), "off");
+        assert_eq!(VerifyTargetsMode::Exists.to_string(), "exists");
+        assert_eq!(VerifyTargetsMode::Exports.to_string(), "exports");
+
+        assert_eq!(SelfImportMode::Error.to_string(), "error");
+        assert_eq!(SelfImportMode::Warn.to_string(), "warn");
+        assert_eq!(SelfImportMode::Off.to_string(), "off");
+
+        assert_eq!(DeepImportMode::Error.to_string(), "error");
+        assert_eq!(DeepImportMode::Warn.to_string(), "warn");
+        assert_eq!(DeepImportMode::Off.to_string(), "off");
+    }
+
+    #[test]
+    fn test_transform_mode_validation() {
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "mode": "check"
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+        assert_eq!(config.mode, TransformMode::Check);
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "mode": "lint"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result.unwrap_err().to_string().contains("Invalid mode"));
+    }
+
+    #[test]
+    fn test_diagnostics_output_validation() {
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "diagnostics_output": "console"
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+        assert_eq!(config.diagnostics_output, DiagnosticsOutput::Console);
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "diagnostics_output": "stderr"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid diagnostics_output"));
+    }
+
+    #[test]
+    fn test_missing_barrel_mode_validation() {
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "missing_barrel_mode": "warn"
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+        assert_eq!(config.missing_barrel_mode, MissingBarrelMode::Warn);
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "missing_barrel_mode": "invalid"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid missing_barrel_mode"));
+    }
+
+    #[test]
+    fn test_self_import_mode_validation() {
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "self_import_mode": "error"
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+        assert_eq!(config.self_import_mode, SelfImportMode::Error);
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "self_import_mode": "invalid"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid self_import_mode"));
+    }
+
+    #[test]
+    fn test_deep_import_mode_validation() {
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "deep_import_mode": "warn"
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+        assert_eq!(config.deep_import_mode, DeepImportMode::Warn);
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "deep_import_mode": "invalid"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid deep_import_mode"));
+    }
+
+    #[test]
+    fn test_verify_targets_validation() {
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "verify_targets": "exports"
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+        assert_eq!(config.verify_targets, VerifyTargetsMode::Exports);
+        assert_eq!(
+            config.barrel_options(&config.patterns[0]).verify_targets,
+            VerifyTargetsMode::Exports
+        );
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "verify_targets": "parse"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid verify_targets 'parse'"));
+    }
+
+    #[test]
+    fn test_output_path_style_validation() {
+        for (style_str, expected_style) in [
+            ("relative", OutputPathStyle::Relative),
+            ("alias", OutputPathStyle::Alias),
+            ("absolute-from-root", OutputPathStyle::AbsoluteFromRoot),
+        ] {
+            let config_json = format!(
+                r#"{{
+                    "patterns": ["src/*/index.ts"],
+                    "output_path_style": "{}"
+                }}"#,
+                style_str
+            );
+
+            let config: Config =
+                serde_json::from_str(&config_json).expect("Failed to parse config JSON");
+            assert_eq!(config.output_path_style, expected_style);
+        }
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "output_path_style": "absolute"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid output_path_style"));
+    }
+
+    #[test]
+    fn test_output_extension_parsing() {
+        for (extension_str, expected_extension) in [
+            ("preserve", OutputExtension::Preserve),
+            ("strip", OutputExtension::Strip),
+            ("js", OutputExtension::Js),
+            ("mjs", OutputExtension::Mjs),
+        ] {
+            let config_json = format!(
+                r#"{{
+                    "patterns": ["src/*/index.ts"],
+                    "output_extension": "{}"
+                }}"#,
+                extension_str
+            );
+
+            let config: Config =
+                serde_json::from_str(&config_json).expect("Failed to parse config JSON");
+            assert_eq!(config.output_extension, expected_extension);
+        }
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "output_extension": { ".ts": ".js", "tsx": "jsx", "": "js" }
+        }"#;
+
+        let config: Config =
+            serde_json::from_str(config_json).expect("Failed to parse config JSON");
+        assert_eq!(
+            config.output_extension,
+            OutputExtension::Mapping(HashMap::from([
+                ("ts".to_string(), "js".to_string()),
+                ("tsx".to_string(), "jsx".to_string()),
+                ("".to_string(), "js".to_string()),
+            ]))
+        );
+
+        let config_json = r#"{
+            "patterns": ["src/*/index.ts"],
+            "output_extension": "cjs"
+        }"#;
+
+        let result: Result<Config, _> = serde_json::from_str(config_json);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("Invalid output_extension"));
     }
 }
@@ -0,0 +1,417 @@
+//! Error types for the barrel files plugin
+//!
+//! This module defines the errors reported by the plugin. Each error carries a stable code
+//! (e.g. `E_UNRESOLVED_EXPORTS`) and the paths involved, and can provide additional notes
+//! and a label, like the position of the offending construct in the barrel file, to be
+//! attached to the SWC diagnostic.
+
+use std::fmt;
+
+use crate::re_export::BarrelError;
+
+/// Position in a file, with 1-based line and column numbers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// The path of the file
+    pub file: String,
+
+    /// The line number
+    pub line: usize,
+
+    /// The column number
+    pub column: usize,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
+/// Error reported by the barrel files plugin
+#[derive(Debug, Clone, PartialEq)]
+pub enum BarrelPluginError {
+    /// A namespace import from a barrel file
+    NamespaceImport,
+
+    /// Imported names that are not exported by the barrel file, with hints on similar
+    /// exports
+    UnresolvedExports {
+        barrel_file: String,
+        names: Vec<String>,
+        hints: Vec<String>,
+    },
+
+    /// A barrel file without any re-exports
+    NoReExports { barrel_file: String },
+
+    /// A file that could not be read
+    FileRead { path: String, reason: String },
+
+    /// A file that could not be parsed
+    FileParse {
+        path: String,
+        reason: String,
+        location: Option<SourceLocation>,
+    },
+
+    /// A barrel file with unsupported constructs
+    InvalidBarrelFile {
+        barrel_file: String,
+        error: BarrelError,
+        location: Option<SourceLocation>,
+    },
+
+    /// An absolute path outside of the current working directory
+    InvalidFilePath { path: String },
+
+    /// An aliased import for which no alias resolves to an existing barrel file
+    AliasNotResolved { import_path: String },
+
+    /// An aliased import matched by two different aliases with the same specificity
+    AmbiguousAlias {
+        import_path: String,
+        selected: String,
+        competing: String,
+    },
+
+    /// A local import matched by patterns that points to a missing barrel file
+    BarrelFileNotFound { barrel_file: String },
+
+    /// A pattern that could not be compiled
+    InvalidPattern { pattern: String, reason: String },
+
+    /// A manifest file that could not be read or parsed
+    InvalidManifest { path: String, reason: String },
+
+    /// A re-export pointing to a module that does not exist
+    MissingTarget {
+        barrel_file: String,
+        name: String,
+        target: String,
+    },
+
+    /// A re-export of a name that is not exported by the target module, with hints on
+    /// similar exports
+    TargetExportNotFound {
+        barrel_file: String,
+        name: String,
+        target: String,
+        original_name: String,
+        hints: Vec<String>,
+    },
+
+    /// A file importing through the barrel file of a directory containing it
+    SelfImport {
+        source_file: String,
+        barrel_file: String,
+    },
+
+    /// An import of a module inside the directory of a barrel file from outside of this
+    /// directory
+    DeepImport {
+        import_path: String,
+        barrel_file: String,
+    },
+
+    /// A source file with barrel imports that the codemod could not rewrite
+    RewriteFailed { path: String },
+}
+
+impl BarrelPluginError {
+    /// Returns the error code
+    pub fn code(&self) -> &'static str {
+        match self {
+            BarrelPluginError::NamespaceImport => "E_NO_NAMESPACE_IMPORTS",
+            BarrelPluginError::UnresolvedExports { .. } | BarrelPluginError::NoReExports { .. } => {
+                "E_UNRESOLVED_EXPORTS"
+            }
+            BarrelPluginError::FileRead { .. } => "E_FILE_READ",
+            BarrelPluginError::FileParse { .. } => "E_FILE_PARSE",
+            BarrelPluginError::InvalidBarrelFile { .. } => "E_INVALID_BARREL_FILE",
+            BarrelPluginError::InvalidFilePath { .. } => "E_INVALID_FILE_PATH",
+            BarrelPluginError::AliasNotResolved { .. }
+            | BarrelPluginError::BarrelFileNotFound { .. } => "E_BARREL_FILE_NOT_FOUND",
+            BarrelPluginError::AmbiguousAlias { .. } => "E_AMBIGUOUS_ALIAS",
+            BarrelPluginError::InvalidPattern { .. } => "E_INVALID_CONFIG",
+            BarrelPluginError::InvalidManifest { .. } => "E_INVALID_MANIFEST",
+            BarrelPluginError::MissingTarget { .. }
+            | BarrelPluginError::TargetExportNotFound { .. } => "E_INVALID_TARGET",
+            BarrelPluginError::SelfImport { .. } => "E_SELF_IMPORT",
+            BarrelPluginError::DeepImport { .. } => "E_DEEP_IMPORT",
+            BarrelPluginError::RewriteFailed { .. } => "E_REWRITE_FAILED",
+        }
+    }
+
+    /// Returns the label to attach to the span of the diagnostic, pointing to the position
+    /// of the offending code in another file
+    pub fn label(&self) -> Option<String> {
+        match self {
+            BarrelPluginError::FileParse {
+                location: Some(location),
+                ..
+            } => Some(format!("syntax error at {}", location)),
+            BarrelPluginError::InvalidBarrelFile {
+                location: Some(location),
+                ..
+            } => Some(format!("unsupported construct at {}", location)),
+            _ => None,
+        }
+    }
+
+    /// Returns additional notes to attach to the diagnostic
+    pub fn notes(&self) -> Vec<String> {
+        match self {
+            BarrelPluginError::UnresolvedExports { hints, .. }
+            | BarrelPluginError::TargetExportNotFound { hints, .. } => hints.clone(),
+            BarrelPluginError::SelfImport { .. } => vec![
+                "Modules inside the directory of a barrel file should import each other directly, as the barrel file may re-export the importing module".to_string(),
+            ],
+            BarrelPluginError::DeepImport { .. } => vec![
+                "Import from the barrel file instead, re-exporting the module from it if needed"
+                    .to_string(),
+            ],
+            _ => Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for BarrelPluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.code())?;
+
+        match self {
+            BarrelPluginError::NamespaceImport => write!(
+                f,
+                "Namespace imports are not supported for barrel file optimization"
+            ),
+            BarrelPluginError::UnresolvedExports {
+                barrel_file, names, ..
+            } => write!(
+                f,
+                "The following exports were not found in the barrel file {}: {}",
+                barrel_file,
+                names.join(", ")
+            ),
+            BarrelPluginError::NoReExports { barrel_file } => {
+                write!(f, "No re-exports found in barrel file: {}", barrel_file)
+            }
+            BarrelPluginError::FileRead { reason, .. } => {
+                write!(f, "Failed to load file: {}", reason)
+            }
+            BarrelPluginError::FileParse { reason, .. } => {
+                write!(f, "Failed to parse file: {}", reason)
+            }
+            BarrelPluginError::InvalidBarrelFile {
+                barrel_file, error, ..
+            } => write!(f, "Invalid barrel file {}: {}", barrel_file, error),
+            BarrelPluginError::InvalidFilePath { path } => write!(
+                f,
+                "Absolute paths not starting with cwd are not supported: {}",
+                path
+            ),
+            BarrelPluginError::AliasNotResolved { import_path } => write!(
+                f,
+                "Could not resolve barrel file for import alias {}",
+                import_path
+            ),
+            BarrelPluginError::BarrelFileNotFound { barrel_file } => {
+                write!(f, "Could not find barrel file {}", barrel_file)
+            }
+            BarrelPluginError::AmbiguousAlias {
+                import_path,
+                selected,
+                competing,
+            } => write!(
+                f,
+                "Import \"{}\" matches aliases \"{}\" and \"{}\" with the same specificity, using \"{}\"",
+                import_path, selected, competing, selected
+            ),
+            BarrelPluginError::InvalidPattern { pattern, reason } => {
+                write!(f, "Failed to compile pattern '{}': {}", pattern, reason)
+            }
+            BarrelPluginError::InvalidManifest { path, reason } => {
+                write!(f, "Failed to load manifest {}: {}", path, reason)
+            }
+            BarrelPluginError::MissingTarget {
+                barrel_file,
+                name,
+                target,
+            } => write!(
+                f,
+                "Module {}, from which the barrel file {} re-exports `{}`, does not exist",
+                target, barrel_file, name
+            ),
+            BarrelPluginError::TargetExportNotFound {
+                barrel_file,
+                name,
+                target,
+                original_name,
+                ..
+            } => write!(
+                f,
+                "Module {} does not export `{}`, re-exported as `{}` by the barrel file {}",
+                target, original_name, name, barrel_file
+            ),
+            BarrelPluginError::SelfImport {
+                source_file,
+                barrel_file,
+            } => write!(
+                f,
+                "{} imports through the barrel file {} of its own module, which creates a circular dependency",
+                source_file, barrel_file
+            ),
+            BarrelPluginError::DeepImport {
+                import_path,
+                barrel_file,
+            } => write!(
+                f,
+                "Import {} bypasses the barrel file {}, which is the public API of its module",
+                import_path, barrel_file
+            ),
+            BarrelPluginError::RewriteFailed { path } => {
+                write!(f, "Failed to rewrite barrel imports in {}", path)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BarrelPluginError {}
+
+/// Warning reported by the barrel files plugin when an import is skipped instead of failing
+/// the build
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    /// The warning message
+    pub message: String,
+
+    /// Additional notes to attach to the diagnostic
+    pub notes: Vec<String>,
+
+    /// The label to attach to the span of the diagnostic
+    pub label: Option<String>,
+}
+
+impl Warning {
+    /// Creates a warning without notes
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            notes: Vec::new(),
+            label: None,
+        }
+    }
+}
+
+impl From<BarrelPluginError> for Warning {
+    fn from(error: BarrelPluginError) -> Self {
+        Self {
+            message: error.to_string(),
+            notes: error.notes(),
+            label: error.label(),
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Problems found while processing an import that do not fail the build
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportIssues {
+    /// Warnings to report at the import
+    pub warnings: Vec<Warning>,
+
+    /// The error for which the import was skipped in `warn` or `off` mode
+    pub skip_reason: Option<BarrelPluginError>,
+}
+
+impl ImportIssues {
+    /// Records an error for which the import is skipped, reporting it as a warning if requested
+    pub fn skip(&mut self, error: BarrelPluginError, warn: bool) {
+        if warn {
+            self.warnings.push(error.clone().into());
+        }
+
+        self.skip_reason = Some(error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_keeps_codes() {
+        let error = BarrelPluginError::UnresolvedExports {
+            barrel_file: "/cwd/src/features/user/index.ts".to_string(),
+            names: vec!["Action".to_string(), "Reducer".to_string()],
+            hints: Vec::new(),
+        };
+        assert_eq!(error.code(), "E_UNRESOLVED_EXPORTS");
+        assert_eq!(
+            error.to_string(),
+            "E_UNRESOLVED_EXPORTS: The following exports were not found in the barrel file /cwd/src/features/user/index.ts: Action, Reducer"
+        );
+        assert!(error.notes().is_empty());
+
+        let error = BarrelPluginError::InvalidFilePath {
+            path: "/other/path".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "E_INVALID_FILE_PATH: Absolute paths not starting with cwd are not supported: /other/path"
+        );
+
+        let error = BarrelPluginError::AmbiguousAlias {
+            import_path: "@app-user-ui".to_string(),
+            selected: "@app-*".to_string(),
+            competing: "@app-*-ui".to_string(),
+        };
+        assert_eq!(error.code(), "E_AMBIGUOUS_ALIAS");
+        assert_eq!(
+            error.to_string(),
+            "E_AMBIGUOUS_ALIAS: Import \"@app-user-ui\" matches aliases \"@app-*\" and \"@app-*-ui\" with the same specificity, using \"@app-*\""
+        );
+    }
+
+    #[test]
+    fn test_label_with_location() {
+        let error = BarrelPluginError::InvalidBarrelFile {
+            barrel_file: "/cwd/src/features/user/index.ts".to_string(),
+            error: BarrelError::WildcardExport(
+                "Wildcard exports are not allowed in barrel files".to_string(),
+            ),
+            location: Some(SourceLocation {
+                file: "/cwd/src/features/user/index.ts".to_string(),
+                line: 3,
+                column: 1,
+            }),
+        };
+
+        assert_eq!(error.code(), "E_INVALID_BARREL_FILE");
+        assert_eq!(
+            error.to_string(),
+            "E_INVALID_BARREL_FILE: Invalid barrel file /cwd/src/features/user/index.ts: Wildcard exports are not supported in barrel files: Wildcard exports are not allowed in barrel files"
+        );
+        assert_eq!(
+            error.label().as_deref(),
+            Some("unsupported construct at /cwd/src/features/user/index.ts:3:1")
+        );
+        assert!(error.notes().is_empty());
+
+        let warning = Warning::from(error);
+        assert_eq!(
+            warning.to_string(),
+            "E_INVALID_BARREL_FILE: Invalid barrel file /cwd/src/features/user/index.ts: Wildcard exports are not supported in barrel files: Wildcard exports are not allowed in barrel files"
+        );
+        assert_eq!(
+            warning.label.as_deref(),
+            Some("unsupported construct at /cwd/src/features/user/index.ts:3:1")
+        );
+        assert!(warning.notes.is_empty());
+    }
+}
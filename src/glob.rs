@@ -0,0 +1,89 @@
+//! Glob module for the barrel files plugin
+//!
+//! This module matches file paths against glob patterns, used to select the source files
+//! to transform. Unlike barrel file patterns, globs support `**` to match any number of
+//! directories.
+
+/// Pre-compiled glob pattern
+#[derive(Debug, Clone)]
+pub struct Glob {
+    /// Pattern segments separated by `/`
+    segments: Vec<String>,
+}
+
+impl Glob {
+    /// Creates a new glob pattern
+    ///
+    /// Supported syntax:
+    ///
+    /// * `**` as a whole segment matches any number of segments, including none
+    /// * `*` matches any number of characters within a segment
+    /// * `?` matches a single character within a segment
+    pub fn new(pattern: &str) -> Self {
+        Glob {
+            segments: pattern.split('/').map(|s| s.to_string()).collect(),
+        }
+    }
+
+    /// Checks if a path matches this glob pattern
+    pub fn matches(&self, path: &str) -> bool {
+        let path_segments: Vec<&str> = path.split('/').collect();
+        let pattern_segments: Vec<&str> = self.segments.iter().map(String::as_str).collect();
+
+        matches_segments(&pattern_segments, &path_segments)
+    }
+}
+
+/// Matches path segments against pattern segments, expanding `**`
+fn matches_segments(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| matches_segments(rest, &path[skip..])),
+        Some((segment, rest)) => match path.split_first() {
+            Some((path_segment, path_rest)) => {
+                matches_segment(segment.as_bytes(), path_segment.as_bytes())
+                    && matches_segments(rest, path_rest)
+            }
+            None => false,
+        },
+    }
+}
+
+/// Matches a single path segment against a pattern segment with `*` and `?`
+fn matches_segment(pattern: &[u8], segment: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => segment.is_empty(),
+        Some((b'*', rest)) => {
+            (0..=segment.len()).any(|skip| matches_segment(rest, &segment[skip..]))
+        }
+        Some((b'?', rest)) => !segment.is_empty() && matches_segment(rest, &segment[1..]),
+        Some((c, rest)) => segment.first() == Some(c) && matches_segment(rest, &segment[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_matching() {
+        let glob = Glob::new("/cwd/**/*.stories.tsx");
+        assert!(glob.matches("/cwd/Button.stories.tsx"));
+        assert!(glob.matches("/cwd/src/ui/Button.stories.tsx"));
+        assert!(!glob.matches("/cwd/src/ui/Button.tsx"));
+        assert!(!glob.matches("/other/Button.stories.tsx"));
+
+        let glob = Glob::new("/cwd/src/legacy/**");
+        assert!(glob.matches("/cwd/src/legacy/page.ts"));
+        assert!(glob.matches("/cwd/src/legacy/pages/home/page.ts"));
+        assert!(!glob.matches("/cwd/src/legacy-v2/page.ts"));
+
+        let glob = Glob::new("/cwd/src/*/page.ts");
+        assert!(glob.matches("/cwd/src/home/page.ts"));
+        assert!(!glob.matches("/cwd/src/home/about/page.ts"));
+
+        let glob = Glob::new("/cwd/src/page-?.ts");
+        assert!(glob.matches("/cwd/src/page-1.ts"));
+        assert!(!glob.matches("/cwd/src/page-10.ts"));
+    }
+}
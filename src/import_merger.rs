@@ -0,0 +1,277 @@
+//! Import merging module for the barrel files plugin
+//!
+//! This module coalesces rewritten import declarations with other imports of the same
+//! module, so that replacing barrel imports does not produce several imports of one file.
+
+use swc_core::common::EqIgnoreSpan;
+use swc_core::ecma::ast::{ImportDecl, ImportSpecifier, ModuleDecl, ModuleItem};
+
+use crate::paths::path_join;
+
+/// Normalizes the source of an import so that different spellings of a path compare equal
+///
+/// # Arguments
+///
+/// * `source_dir` - The directory containing the current source file
+/// * `source` - The source of the import declaration
+///
+/// # Returns
+///
+/// The absolute path for relative imports, the source as is otherwise
+fn normalize_source(source_dir: &str, source: &str) -> String {
+    if source.starts_with('.') {
+        path_join(source_dir, source)
+    } else {
+        source.to_string()
+    }
+}
+
+/// Checks whether two import specifiers declare the same binding the same way
+fn is_same_specifier(a: &ImportSpecifier, b: &ImportSpecifier) -> bool {
+    match (a, b) {
+        (ImportSpecifier::Default(a), ImportSpecifier::Default(b)) => a.local.sym == b.local.sym,
+        (ImportSpecifier::Named(a), ImportSpecifier::Named(b)) => {
+            a.local.sym == b.local.sym
+                && a.is_type_only == b.is_type_only
+                && a.imported.eq_ignore_span(&b.imported)
+        }
+        _ => false,
+    }
+}
+
+/// Checks whether the specifiers of one import declaration can be moved into another one
+///
+/// Both declarations must import the same module the same way (type-only imports,
+/// import attributes and phase), must not use namespace imports, and together must not
+/// declare more than one default import.
+fn can_merge(target: &ImportDecl, import: &ImportDecl) -> bool {
+    let has_namespace = |import: &ImportDecl| {
+        import
+            .specifiers
+            .iter()
+            .any(|specifier| matches!(specifier, ImportSpecifier::Namespace(_)))
+    };
+    let default_local = |import: &ImportDecl| {
+        import
+            .specifiers
+            .iter()
+            .find_map(|specifier| match specifier {
+                ImportSpecifier::Default(default) => Some(default.local.sym.clone()),
+                _ => None,
+            })
+    };
+
+    if target.type_only != import.type_only
+        || target.phase != import.phase
+        || !target.with.eq_ignore_span(&import.with)
+        || has_namespace(target)
+        || has_namespace(import)
+    {
+        return false;
+    }
+
+    match (default_local(target), default_local(import)) {
+        (Some(target_default), Some(import_default)) => target_default == import_default,
+        _ => true,
+    }
+}
+
+/// Moves the specifiers of an import declaration into another one, skipping duplicates
+fn merge_into(target: &mut ImportDecl, import: ImportDecl) {
+    for specifier in import.specifiers {
+        if target
+            .specifiers
+            .iter()
+            .any(|existing| is_same_specifier(existing, &specifier))
+        {
+            continue;
+        }
+
+        // The default import has to go first in the declaration
+        if matches!(specifier, ImportSpecifier::Default(_)) {
+            target.specifiers.insert(0, specifier);
+        } else {
+            target.specifiers.push(specifier);
+        }
+    }
+}
+
+/// Merges rewritten import declarations with other imports of the same module
+///
+/// Rewritten imports are recognized by the dummy span of their source, which is only
+/// produced by the transform. An import is merged into the first earlier import of the
+/// same normalized path if at least one of them is rewritten, so imports written by hand
+/// are never merged with each other.
+///
+/// # Arguments
+///
+/// * `items` - The module items to update
+/// * `source_dir` - The directory containing the current source file
+pub fn merge_imports(items: &mut Vec<ModuleItem>, source_dir: &str) {
+    let mut merged_items: Vec<ModuleItem> = Vec::with_capacity(items.len());
+
+    // Imports collected so far: normalized source, whether the import is rewritten and
+    // the position of the import in `merged_items`
+    let mut imports: Vec<(String, bool, usize)> = Vec::new();
+
+    for item in items.drain(..) {
+        let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item else {
+            merged_items.push(item);
+            continue;
+        };
+
+        let source = normalize_source(source_dir, &import.src.value);
+        let is_rewritten = import.src.span.is_dummy();
+
+        let target_index = imports
+            .iter()
+            .filter(|(target_source, target_rewritten, _)| {
+                *target_source == source && (is_rewritten || *target_rewritten)
+            })
+            .map(|(_, _, index)| *index)
+            .find(|index| match &merged_items[*index] {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(target)) => can_merge(target, &import),
+                _ => false,
+            });
+
+        match target_index {
+            Some(index) => {
+                if let ModuleItem::ModuleDecl(ModuleDecl::Import(target)) = &mut merged_items[index]
+                {
+                    merge_into(target, import);
+                }
+            }
+            None => {
+                imports.push((source, is_rewritten, merged_items.len()));
+                merged_items.push(ModuleItem::ModuleDecl(ModuleDecl::Import(import)));
+            }
+        }
+    }
+
+    *items = merged_items;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_core::common::sync::Lrc;
+    use swc_core::common::{FileName, SourceMap, DUMMY_SP};
+    use swc_core::ecma::parser::{parse_file_as_module, Syntax, TsSyntax};
+
+    fn parse(code: &str) -> Vec<ModuleItem> {
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(Lrc::new(FileName::Anon), code.to_string());
+
+        parse_file_as_module(
+            &fm,
+            Syntax::Typescript(TsSyntax::default()),
+            Default::default(),
+            None,
+            &mut vec![],
+        )
+        .unwrap()
+        .body
+    }
+
+    /// Marks the imports at the given positions as rewritten
+    fn rewrite(items: &mut [ModuleItem], indices: &[usize]) {
+        for index in indices {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = &mut items[*index] {
+                import.src.span = DUMMY_SP;
+            }
+        }
+    }
+
+    fn summarize(items: &[ModuleItem]) -> Vec<String> {
+        items
+            .iter()
+            .map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    let specifiers = import
+                        .specifiers
+                        .iter()
+                        .map(|specifier| match specifier {
+                            ImportSpecifier::Named(named) => named.local.sym.to_string(),
+                            ImportSpecifier::Default(default) => {
+                                format!("default {}", default.local.sym)
+                            }
+                            ImportSpecifier::Namespace(namespace) => {
+                                format!("* as {}", namespace.local.sym)
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    format!("{} <- {}", import.src.value, specifiers.join(", "))
+                }
+                _ => "statement".to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_merge_with_existing_import() {
+        let mut items = parse(
+            r#"
+            import { A } from "./ui/A";
+            console.log(A);
+            import { B } from "../pages/ui/B";
+            import { A as A2, C } from "./ui/A";
+            "#,
+        );
+        rewrite(&mut items, &[2, 3]);
+
+        merge_imports(&mut items, "/cwd/pages");
+
+        assert_eq!(
+            summarize(&items),
+            vec!["./ui/A <- A, A2, C", "statement", "../pages/ui/B <- B"]
+        );
+    }
+
+    #[test]
+    fn test_skip_duplicates() {
+        let mut items = parse(
+            r#"
+            import { A } from "./ui/A";
+            import { A, B } from "./ui/A";
+            import Default, { A } from "./ui/A";
+            "#,
+        );
+        rewrite(&mut items, &[0, 1, 2]);
+
+        merge_imports(&mut items, "/cwd");
+
+        assert_eq!(summarize(&items), vec!["./ui/A <- default Default, A, B"]);
+    }
+
+    #[test]
+    fn test_keep_incompatible_imports() {
+        let mut items = parse(
+            r#"
+            import { A } from "./a";
+            import type { B } from "./a";
+            import { C } from "./a" with { type: "json" };
+            import * as D from "./a";
+            import E from "./a";
+            import F from "./a";
+            import { G } from "./b";
+            import { H } from "./b";
+            "#,
+        );
+        rewrite(&mut items, &[0, 1, 2, 3, 4, 5]);
+
+        merge_imports(&mut items, "/cwd");
+
+        assert_eq!(
+            summarize(&items),
+            vec![
+                "./a <- default E, A",
+                "./a <- B",
+                "./a <- C",
+                "./a <- * as D",
+                "./a <- default F",
+                "./b <- G",
+                "./b <- H",
+            ]
+        );
+    }
+}
@@ -1,36 +1,395 @@
-use crate::config::{Config, InvalidBarrelMode, UnsupportedImportMode};
-use crate::paths::{dirname, path_join, resolve_relative_path};
-use crate::re_export::{analyze_barrel_file, ReExport};
+use crate::cache::{
+    get_analysis, get_target_exports, insert_analysis, insert_target_exports, BarrelAnalysis,
+    FileStamp, TargetExports,
+};
+use crate::config::{
+    BarrelOptions, InvalidBarrelMode, OutputExtension, UnsupportedImportMode, VerifyTargetsMode,
+};
+use crate::errors::{BarrelPluginError, ImportIssues, SourceLocation};
+use crate::manifest::LoadedManifest;
+use crate::paths::{dirname, path_join, resolve_module_file, resolve_source_file};
+use crate::re_export::{analyze_barrel_file, exported_names, ReExport};
+use crate::suggestions::similar_names;
 use indexmap::IndexMap;
-use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
-use std::sync::Mutex;
 use swc_core::common::sync::Lrc;
-use swc_core::common::DUMMY_SP;
 use swc_core::common::{
     errors::{ColorConfig, Handler},
     SourceMap,
 };
+use swc_core::common::{Span, Spanned, DUMMY_SP};
 use swc_core::ecma::ast::Module;
 use swc_core::ecma::ast::{
     ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier, ModuleExportName,
     Str,
 };
-use swc_core::ecma::parser::{parse_file_as_module, Syntax};
+use swc_core::ecma::parser::{parse_file_as_module, EsSyntax, Syntax, TsSyntax};
+
+/// Reads the re-exports of the barrel files involved in transforming an import
+pub struct BarrelReader<'a> {
+    /// Returns the settings for a path if it is a barrel file, used to follow nested
+    /// barrel files
+    pub match_barrel: &'a dyn Fn(&str) -> Option<BarrelOptions>,
+
+    /// Directory in which the analyses of barrel files are persisted between builds
+    pub cache_dir: Option<&'a str>,
+
+    /// Manifest with the precomputed re-exports of barrel files
+    pub manifest: Option<&'a LoadedManifest>,
+}
+
+/// Extensions of script modules that can be rewritten by `output_extension`
+const SCRIPT_EXTENSIONS: [&str; 8] = ["ts", "tsx", "mts", "cts", "js", "jsx", "mjs", "cjs"];
+
+/// Rewrites the extension of a module path
+///
+/// Paths without a script extension that point to a directory get an explicit `/index`
+/// before a new extension is added, as directory imports are not resolved in strict ESM.
+/// Paths to existing files with other extensions are kept as is.
+///
+/// # Arguments
+///
+/// * `module_path` - The path of the module
+/// * `output_extension` - How to rewrite the extension
+///
+/// # Returns
+///
+/// The module path with the rewritten extension
+fn rewrite_extension(module_path: &str, output_extension: &OutputExtension) -> String {
+    let file_name = module_path.rsplit('/').next().unwrap_or_default();
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, extension)| extension)
+        .filter(|extension| SCRIPT_EXTENSIONS.contains(extension));
+
+    let new_extension = match (output_extension, extension) {
+        (OutputExtension::Preserve, _) => return module_path.to_string(),
+        (OutputExtension::Strip, _) => "",
+        (OutputExtension::Js, _) => "js",
+        (OutputExtension::Mjs, _) => "mjs",
+        (OutputExtension::Mapping(mapping), extension) => {
+            match mapping.get(extension.unwrap_or_default()) {
+                Some(new_extension) => new_extension.as_str(),
+                None => return module_path.to_string(),
+            }
+        }
+    };
 
-/// Cache for parsed barrel files to avoid re-parsing the same file
-static BARREL_CACHE: Lazy<Mutex<HashMap<String, Option<Vec<ReExport>>>>> =
-    Lazy::new(|| Mutex::new(HashMap::new()));
+    let stem = match extension {
+        Some(extension) => &module_path[..module_path.len() - extension.len() - 1],
+        None if new_extension.is_empty() => return module_path.to_string(),
+        None => match resolve_module_file(module_path) {
+            // Existing files with other extensions, e.g. `styles.css`
+            Some(module_file) if module_file == module_path => return module_path.to_string(),
+            Some(module_file) if module_file.starts_with(&format!("{}/index.", module_path)) => {
+                return format!("{}/index.{}", module_path, new_extension);
+            }
+            _ => module_path,
+        },
+    };
+
+    if new_extension.is_empty() {
+        stem.to_string()
+    } else {
+        format!("{}.{}", stem, new_extension)
+    }
+}
 
 /// Resolves the import path from the barrel file directory and re-export source path
-fn resolve_import_path(barrel_file_dir: &str, source_dir: &str, re_export: &ReExport) -> String {
+///
+/// Package imports are kept as is, local modules get the output extension and are formatted
+/// with `output_path`.
+fn resolve_import_path(
+    barrel_file_dir: &str,
+    re_export: &ReExport,
+    output_extension: &OutputExtension,
+    output_path: &dyn Fn(&str) -> Result<String, BarrelPluginError>,
+) -> Result<String, BarrelPluginError> {
+    if !re_export.source_path.starts_with('.') {
+        return Ok(re_export.source_path.clone());
+    }
+
+    let target_path = path_join(barrel_file_dir, &re_export.source_path);
+    output_path(&rewrite_extension(&target_path, output_extension))
+}
+
+/// Lists the names exported by a re-export target, caching them by file stamp
+fn cached_target_exports(file_path: &str) -> Result<TargetExports, BarrelPluginError> {
+    let stamp = FileStamp::of(file_path);
+
+    if let Some(exports) = stamp
+        .as_ref()
+        .and_then(|stamp| get_target_exports(file_path, stamp))
+    {
+        return Ok(exports);
+    }
+
+    let (ast, _) = parse_file(file_path, syntax_for(file_path))?;
+    let exports = exported_names(&ast);
+
+    if let Some(stamp) = stamp {
+        insert_target_exports(file_path, stamp, exports.clone());
+    }
+
+    Ok(exports)
+}
+
+/// Verifies that the module targeted by a re-export exists and exports the re-exported name
+///
+/// Package sources are not verified. Targets whose exports cannot be determined statically
+/// are only checked for existence.
+///
+/// # Arguments
+///
+/// * `barrel_file` - The path to the imported barrel file
+/// * `barrel_file_dir` - The directory of the barrel file containing the re-export
+/// * `re_export` - The re-export to verify
+/// * `mode` - How to verify the target
+fn verify_target(
+    barrel_file: &str,
+    barrel_file_dir: &str,
+    re_export: &ReExport,
+    mode: VerifyTargetsMode,
+) -> Result<(), BarrelPluginError> {
+    if mode == VerifyTargetsMode::Off || !re_export.source_path.starts_with('.') {
+        return Ok(());
+    }
+
+    let target_path = path_join(barrel_file_dir, &re_export.source_path);
+    let Some(target) = resolve_source_file(&target_path) else {
+        return Err(BarrelPluginError::MissingTarget {
+            barrel_file: barrel_file.to_string(),
+            name: re_export.exported_name.clone(),
+            target: target_path,
+        });
+    };
+
+    if mode == VerifyTargetsMode::Exists {
+        return Ok(());
+    }
+
+    let Some(exports) = cached_target_exports(&target)? else {
+        return Ok(());
+    };
+
+    if exports.contains(&re_export.original_name) {
+        return Ok(());
+    }
+
+    // Sorted, as the order of the exports decides between equally similar names
+    let mut candidates: Vec<&str> = exports.iter().map(String::as_str).collect();
+    candidates.sort_unstable();
+
+    let similar = similar_names(&re_export.original_name, candidates);
+    let hints = if similar.is_empty() {
+        Vec::new()
+    } else {
+        let suggestions: Vec<String> = similar.iter().map(|name| format!("`{}`", name)).collect();
+        vec![format!(
+            "{} exports {} instead",
+            target,
+            suggestions.join(" or ")
+        )]
+    };
+
+    Err(BarrelPluginError::TargetExportNotFound {
+        barrel_file: barrel_file.to_string(),
+        name: re_export.exported_name.clone(),
+        target,
+        original_name: re_export.original_name.clone(),
+        hints,
+    })
+}
+
+/// Follows a re-export through nested barrel files down to the module that declares it
+///
+/// # Arguments
+///
+/// * `barrel_file_dir` - The directory of the barrel file containing the re-export
+/// * `re_export` - The re-export to follow
+/// * `reader` - Reads the re-exports of nested barrel files
+/// * `visited` - Barrel files already visited, used to stop on circular re-exports
+/// * `issues` - Collects the warnings reported for nested barrel files
+///
+/// # Returns
+///
+/// The directory of the last barrel file in the chain and the re-export pointing
+/// from it to the source module
+fn follow_nested_re_export(
+    barrel_file_dir: &str,
+    re_export: &ReExport,
+    reader: &BarrelReader,
+    visited: &mut Vec<String>,
+    issues: &mut ImportIssues,
+) -> Result<(String, ReExport), BarrelPluginError> {
+    let unresolved = || Ok((barrel_file_dir.to_string(), re_export.clone()));
+
     if !re_export.source_path.starts_with('.') {
-        return re_export.source_path.clone();
+        return unresolved();
     }
 
     let target_path = path_join(barrel_file_dir, &re_export.source_path);
-    resolve_relative_path(source_dir, &target_path).unwrap()
+    let Some(nested_barrel) = resolve_module_file(&target_path) else {
+        return unresolved();
+    };
+
+    if visited.contains(&nested_barrel) {
+        return unresolved();
+    }
+
+    let Some(nested_options) = (reader.match_barrel)(&nested_barrel) else {
+        return unresolved();
+    };
+
+    let Some(nested_exports) =
+        parse_barrel_file_exports(&nested_barrel, &nested_options, reader, issues)?
+    else {
+        return unresolved();
+    };
+
+    let Some(nested_export) = nested_exports
+        .iter()
+        .find(|nested_export| nested_export.exported_name == re_export.original_name)
+    else {
+        let names = vec![re_export.original_name.clone()];
+
+        return Err(BarrelPluginError::UnresolvedExports {
+            hints: unresolved_export_hints(&nested_barrel, &nested_exports, &names, reader),
+            barrel_file: nested_barrel,
+            names,
+        });
+    };
+
+    let nested_re_export = ReExport {
+        exported_name: re_export.exported_name.clone(),
+        source_path: nested_export.source_path.clone(),
+        original_name: nested_export.original_name.clone(),
+        is_default: nested_export.is_default,
+        is_type_only: re_export.is_type_only || nested_export.is_type_only,
+    };
+
+    visited.push(nested_barrel.clone());
+
+    follow_nested_re_export(
+        &dirname(&nested_barrel),
+        &nested_re_export,
+        reader,
+        visited,
+        issues,
+    )
+}
+
+/// Formats an export name for a hint, marking type-only exports
+fn describe_export(re_export: &ReExport) -> String {
+    if re_export.is_type_only {
+        format!("`{}` (type-only export)", re_export.exported_name)
+    } else {
+        format!("`{}`", re_export.exported_name)
+    }
+}
+
+/// Explains why imported names could not be found in a barrel file
+///
+/// Suggests exports with similar names, and points to nested barrel files re-exported by
+/// the barrel file that export the missing names.
+///
+/// # Arguments
+///
+/// * `barrel_file` - The path to the barrel file
+/// * `re_exports` - The re-exports of the barrel file
+/// * `missing_names` - The imported names that were not found
+/// * `reader` - Reads the re-exports of nested barrel files
+///
+/// # Returns
+///
+/// A hint for each missing name that has similar exports or is exported by a nested barrel
+fn unresolved_export_hints(
+    barrel_file: &str,
+    re_exports: &[ReExport],
+    missing_names: &[String],
+    reader: &BarrelReader,
+) -> Vec<String> {
+    let barrel_file_dir = dirname(barrel_file);
+    let lenient_options = BarrelOptions {
+        invalid_barrel_mode: InvalidBarrelMode::Off,
+        ..Default::default()
+    };
+
+    // Re-exported modules that are barrel files themselves, in barrel file order
+    let mut nested_barrels: Vec<(String, Vec<ReExport>)> = Vec::new();
+    for re_export in re_exports {
+        if !re_export.source_path.starts_with('.') {
+            continue;
+        }
+
+        let target_path = path_join(&barrel_file_dir, &re_export.source_path);
+        let Some(nested_barrel) = resolve_module_file(&target_path) else {
+            continue;
+        };
+
+        if nested_barrel == barrel_file
+            || nested_barrels
+                .iter()
+                .any(|(path, _)| *path == nested_barrel)
+        {
+            continue;
+        }
+
+        if let Ok(Some(nested_exports)) = parse_barrel_file_exports(
+            &nested_barrel,
+            &lenient_options,
+            reader,
+            &mut ImportIssues::default(),
+        ) {
+            nested_barrels.push((nested_barrel, nested_exports));
+        }
+    }
+
+    let mut hints = Vec::new();
+
+    for name in missing_names.iter().filter(|name| *name != "default") {
+        let similar = similar_names(
+            name,
+            re_exports
+                .iter()
+                .map(|re_export| re_export.exported_name.as_str()),
+        );
+
+        if !similar.is_empty() {
+            let suggestions: Vec<String> = similar
+                .iter()
+                .filter_map(|similar_name| {
+                    re_exports
+                        .iter()
+                        .find(|re_export| re_export.exported_name == *similar_name)
+                        .map(describe_export)
+                })
+                .collect();
+
+            hints.push(format!(
+                "Did you mean {} instead of `{}`?",
+                suggestions.join(" or "),
+                name
+            ));
+        }
+
+        for (nested_barrel, nested_exports) in &nested_barrels {
+            if let Some(nested_export) = nested_exports
+                .iter()
+                .find(|nested_export| nested_export.exported_name == *name)
+            {
+                hints.push(format!(
+                    "{} is exported by the nested barrel file {}, but is not re-exported by {}",
+                    describe_export(nested_export),
+                    nested_barrel,
+                    barrel_file
+                ));
+            }
+        }
+    }
+
+    hints
 }
 
 /// Creates a default import specifier
@@ -44,7 +403,36 @@ fn create_default_specifier(
     })
 }
 
+/// Creates the name of an export in an import specifier
+///
+/// Names which are not identifiers, like `"a-b"`, can only be imported as string literals.
+fn create_export_name(name: &str) -> ModuleExportName {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(swc_core::ecma::ast::Ident::is_valid_start)
+        && chars.all(swc_core::ecma::ast::Ident::is_valid_continue);
+
+    if is_identifier {
+        ModuleExportName::Ident(swc_core::ecma::ast::Ident {
+            span: DUMMY_SP,
+            sym: name.into(),
+            optional: false,
+            ctxt: Default::default(),
+        })
+    } else {
+        ModuleExportName::Str(Str {
+            span: DUMMY_SP,
+            value: name.into(),
+            raw: None,
+        })
+    }
+}
+
 /// Creates a named import specifier
+///
+/// Default exports are imported as `default` when a default specifier cannot be used,
+/// e.g. for type-only imports (`import { type default as Props }`).
 fn create_named_specifier(
     span: swc_core::common::Span,
     local_name: &swc_core::ecma::ast::Ident,
@@ -54,31 +442,63 @@ fn create_named_specifier(
     ImportSpecifier::Named(ImportNamedSpecifier {
         span,
         local: local_name.clone(),
-        imported: if !re_export.is_default {
-            // For named exports, check if we need to add the 'as' clause
-            if local_name.sym != re_export.original_name {
-                // Only add the 'as' clause when the original name is different from the local name
-                // This handles both cases:
-                // 1. When the export was renamed in the barrel file (setVisible as toggle)
-                // 2. When the import is renamed in the consumer file (toggle as switcher)
-                Some(ModuleExportName::Ident(swc_core::ecma::ast::Ident {
-                    span: DUMMY_SP,
-                    sym: re_export.original_name.clone().into(),
-                    optional: false,
-                    ctxt: Default::default(),
-                }))
-            } else {
-                // If the original name is the same as the local name, don't add the 'as' clause
-                None
-            }
-        } else {
-            // For default exports, we don't need to specify the imported name
-            None
-        },
+        // Only add the 'as' clause when the original name is different from the local name
+        // This handles both cases:
+        // 1. When the export was renamed in the barrel file (setVisible as toggle)
+        // 2. When the import is renamed in the consumer file (toggle as switcher)
+        imported: (local_name.sym != re_export.original_name)
+            .then(|| create_export_name(&re_export.original_name)),
         is_type_only,
     })
 }
 
+/// Creates the import specifier importing a re-export of a barrel file
+///
+/// The specifier of the consumer is mapped to the original export of the source module,
+/// covering every combination of default and named imports and exports:
+///
+/// * `import { Button }` from `export { default as Button }` becomes `import Button`
+/// * `import Button` from `export { Button as default }` becomes `import { Button }`
+/// * `import Button` from `export { default }` stays `import Button`
+///
+/// # Arguments
+///
+/// * `specifier` - The import specifier of the consumer
+/// * `re_export` - The re-export of the barrel file the specifier imports
+///
+/// # Returns
+///
+/// The new import specifier, `None` for namespace imports
+fn create_specifier(specifier: &ImportSpecifier, re_export: &ReExport) -> Option<ImportSpecifier> {
+    match specifier {
+        ImportSpecifier::Named(named) => {
+            if re_export.is_default && !named.is_type_only {
+                Some(create_default_specifier(named.span, &named.local))
+            } else {
+                Some(create_named_specifier(
+                    named.span,
+                    &named.local,
+                    re_export,
+                    named.is_type_only,
+                ))
+            }
+        }
+        ImportSpecifier::Default(default) => {
+            if re_export.is_default {
+                Some(create_default_specifier(default.span, &default.local))
+            } else {
+                Some(create_named_specifier(
+                    default.span,
+                    &default.local,
+                    re_export,
+                    false,
+                ))
+            }
+        }
+        ImportSpecifier::Namespace(_) => None,
+    }
+}
+
 /// Extracts the imported name from a named import specifier
 fn extract_imported_name(named: &ImportNamedSpecifier) -> String {
     named
@@ -91,30 +511,100 @@ fn extract_imported_name(named: &ImportNamedSpecifier) -> String {
         .unwrap_or_else(|| named.local.sym.to_string())
 }
 
+/// Formats an import declaration as source code on a single line
+///
+/// # Arguments
+///
+/// * `import_decl` - The import declaration to format
+///
+/// # Returns
+///
+/// The import statement, e.g. `import Card, { type CardProps } from "./ui/Card"`
+pub fn format_import(import_decl: &ImportDecl) -> String {
+    format!(
+        "import {} from \"{}\"",
+        format_import_clause(import_decl),
+        import_decl.src.value
+    )
+}
+
+/// Formats the bindings of an import declaration, between `import` and `from`
+///
+/// # Arguments
+///
+/// * `import_decl` - The import declaration to format
+///
+/// # Returns
+///
+/// The bindings, e.g. `Card, { type CardProps }` or `type { User }`
+pub fn format_import_clause(import_decl: &ImportDecl) -> String {
+    let mut bindings = Vec::new();
+    let mut named = Vec::new();
+
+    for specifier in &import_decl.specifiers {
+        match specifier {
+            ImportSpecifier::Default(default) => bindings.push(default.local.sym.to_string()),
+            ImportSpecifier::Namespace(namespace) => {
+                bindings.push(format!("* as {}", namespace.local.sym))
+            }
+            ImportSpecifier::Named(specifier) => {
+                let type_prefix = if specifier.is_type_only { "type " } else { "" };
+                let local = specifier.local.sym.to_string();
+
+                let binding = match &specifier.imported {
+                    Some(ModuleExportName::Ident(ident)) if ident.sym != local => {
+                        format!("{} as {}", ident.sym, local)
+                    }
+                    Some(ModuleExportName::Str(str)) => format!("\"{}\" as {}", str.value, local),
+                    _ => local,
+                };
+
+                named.push(format!("{}{}", type_prefix, binding));
+            }
+        }
+    }
+
+    if !named.is_empty() {
+        bindings.push(format!("{{ {} }}", named.join(", ")));
+    }
+
+    format!(
+        "{}{}",
+        if import_decl.type_only { "type " } else { "" },
+        bindings.join(", ")
+    )
+}
+
 /// Transforms an import declaration by replacing barrel imports with direct imports
 ///
 /// # Arguments
 ///
-/// * `source_dir` - The directory containing the current source file
 /// * `import_decl` - The import declaration to transform
 /// * `barrel_file` - The path to the barrel file
-/// * `config` - The plugin configuration
+/// * `options` - The settings for the barrel file
+/// * `output_extension` - How to rewrite the extensions of the source modules
+/// * `reader` - Reads the re-exports of the barrel file and nested barrel files
+/// * `output_path` - Formats the path to a source module for the new import declarations
+/// * `issues` - Collects the warnings and the reason for skipping the import
 ///
 /// # Returns
 ///
 /// A vector of new import declarations that directly import from the original source files
 pub fn transform_import(
-    source_dir: &str,
     import_decl: &ImportDecl,
     barrel_file: &str,
-    config: &Config,
-) -> Result<Option<Vec<ImportDecl>>, String> {
+    options: &BarrelOptions,
+    output_extension: &OutputExtension,
+    reader: &BarrelReader,
+    output_path: &dyn Fn(&str) -> Result<String, BarrelPluginError>,
+    issues: &mut ImportIssues,
+) -> Result<Option<Vec<ImportDecl>>, BarrelPluginError> {
     let mut new_imports: IndexMap<String, Vec<ImportSpecifier>> = IndexMap::new();
     let mut missing_exports = Vec::new();
 
     let barrel_file_dir = dirname(barrel_file);
 
-    let re_exports = parse_barrel_file_exports(barrel_file, config)?;
+    let re_exports = parse_barrel_file_exports(barrel_file, options, reader, issues)?;
 
     if let Some(re_exports) = re_exports {
         let mut import_specifiers_map = HashMap::new();
@@ -128,52 +618,56 @@ pub fn transform_import(
                 ImportSpecifier::Default(_) => {
                     import_specifiers_map.insert("default".to_string(), specifier);
                 }
-                ImportSpecifier::Namespace(_) => match config.unsupported_import_mode {
+                ImportSpecifier::Namespace(_) => match options.unsupported_import_mode {
                     UnsupportedImportMode::Error => {
-                        return Err(
-                            "E_NO_NAMESPACE_IMPORTS: Namespace imports are not supported for barrel file optimization".to_string(),
-                        );
+                        return Err(BarrelPluginError::NamespaceImport);
                     }
                     UnsupportedImportMode::Warn => {
-                        eprintln!("Warning: Namespace imports are not supported for barrel file optimization. Import from {} will be skipped.", import_decl.src.value);
+                        issues.skip(BarrelPluginError::NamespaceImport, true);
+                    }
+                    UnsupportedImportMode::Off => {
+                        issues.skip(BarrelPluginError::NamespaceImport, false);
                     }
-                    UnsupportedImportMode::Off => {}
                 },
             }
         }
 
         let mut found_exports = HashSet::new();
-        let mut has_default_export = false;
 
         // Iterate through re_exports in barrel file order to preserve order
         for re_export in &re_exports {
             found_exports.insert(&re_export.exported_name);
-            if re_export.is_default {
-                has_default_export = true;
-            }
 
             if let Some(specifier) = import_specifiers_map.get(&re_export.exported_name) {
-                let import_path = resolve_import_path(&barrel_file_dir, source_dir, re_export);
-
-                let new_specifier = match specifier {
-                    ImportSpecifier::Named(named) => {
-                        if re_export.is_default {
-                            create_default_specifier(named.span, &named.local)
-                        } else {
-                            create_named_specifier(
-                                named.span,
-                                &named.local,
-                                re_export,
-                                named.is_type_only,
-                            )
-                        }
-                    }
-                    ImportSpecifier::Default(default) => {
-                        create_default_specifier(default.span, &default.local)
-                    }
-                    ImportSpecifier::Namespace(_) => {
-                        continue;
-                    }
+                let (target_barrel_dir, re_export) = if options.follow_nested {
+                    follow_nested_re_export(
+                        &barrel_file_dir,
+                        re_export,
+                        reader,
+                        &mut vec![barrel_file.to_string()],
+                        issues,
+                    )?
+                } else {
+                    (barrel_file_dir.clone(), re_export.clone())
+                };
+                let re_export = &re_export;
+
+                verify_target(
+                    barrel_file,
+                    &target_barrel_dir,
+                    re_export,
+                    options.verify_targets,
+                )?;
+
+                let import_path = resolve_import_path(
+                    &target_barrel_dir,
+                    re_export,
+                    output_extension,
+                    output_path,
+                )?;
+
+                let Some(new_specifier) = create_specifier(specifier, re_export) else {
+                    continue;
                 };
 
                 new_imports
@@ -184,23 +678,21 @@ pub fn transform_import(
         }
 
         // Check for any imported specifiers that weren't found in the barrel file
+        // The default export of the barrel file is the one exported as `default`, while
+        // `export { default as Button }` is a named export of the original default export
         for imported_name in import_specifiers_map.keys() {
-            if imported_name == "default" {
-                if !has_default_export {
-                    missing_exports.push("default".to_string());
-                }
-            } else if !found_exports.contains(imported_name) {
+            if !found_exports.contains(imported_name) {
                 missing_exports.push(imported_name.clone());
             }
         }
 
         // Check if any imports were not found in the barrel file
         if !missing_exports.is_empty() {
-            return Err(format!(
-            "E_UNRESOLVED_EXPORTS: The following exports were not found in the barrel file {}: {}",
-            barrel_file,
-            missing_exports.join(", ")
-        ));
+            return Err(BarrelPluginError::UnresolvedExports {
+                barrel_file: barrel_file.to_string(),
+                hints: unresolved_export_hints(barrel_file, &re_exports, &missing_exports, reader),
+                names: missing_exports,
+            });
         }
 
         // Create new import declarations for each source path
@@ -229,83 +721,578 @@ pub fn transform_import(
     }
 }
 
+/// Finds the position of a span in a file
+pub fn source_location(cm: &SourceMap, file_path: &str, span: Span) -> Option<SourceLocation> {
+    if span.is_dummy() {
+        return None;
+    }
+
+    let loc = cm.lookup_char_pos(span.lo);
+
+    Some(SourceLocation {
+        file: file_path.to_string(),
+        line: loc.line,
+        column: loc.col_display + 1,
+    })
+}
+
+/// Selects the syntax to parse a source file with from its extension
+///
+/// Used for the modules targeted by re-exports and the files rewritten by the codemod, while
+/// barrel files are always parsed as TypeScript.
+pub fn syntax_for(filename: &str) -> Syntax {
+    let extension = filename.rsplit_once('.').map(|(_, extension)| extension);
+
+    match extension {
+        Some("js" | "jsx" | "mjs" | "cjs") => Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+        Some("tsx") => Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Typescript(TsSyntax::default()),
+    }
+}
+
 /// Parses a file into an AST
-fn parse_file(file_path: &str) -> Result<Module, String> {
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the file
+/// * `syntax` - The syntax to parse the file with, TypeScript for barrel files
+///
+/// # Returns
+///
+/// The AST and the source map used to locate its spans
+pub fn parse_file(
+    file_path: &str,
+    syntax: Syntax,
+) -> Result<(Module, Lrc<SourceMap>), BarrelPluginError> {
     let cm: Lrc<SourceMap> = Default::default();
     let _handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(cm.clone()));
 
     let fm = match cm.load_file(Path::new(file_path)) {
         Ok(fm) => fm,
-        Err(e) => return Err(format!("E_FILE_READ: Failed to load file: {}", e)),
+        Err(e) => {
+            return Err(BarrelPluginError::FileRead {
+                path: file_path.to_string(),
+                reason: e.to_string(),
+            })
+        }
     };
 
-    let syntax = Syntax::Typescript(Default::default());
-
     match parse_file_as_module(&fm, syntax, Default::default(), None, &mut vec![]) {
-        Ok(module) => Ok(module),
-        Err(e) => Err(format!("E_FILE_PARSE: Failed to parse file: {:?}", e)),
+        Ok(module) => Ok((module, cm)),
+        Err(e) => Err(BarrelPluginError::FileParse {
+            path: file_path.to_string(),
+            location: source_location(&cm, file_path, e.span()),
+            reason: format!("{:?}", e),
+        }),
+    }
+}
+
+/// Parses a barrel file and extracts re-export information
+///
+/// # Returns
+///
+/// The analysis of the barrel file, `Err` if the file cannot be read or parsed
+pub fn analyze_barrel(file_path: &str) -> Result<BarrelAnalysis, BarrelPluginError> {
+    let (ast, cm) = parse_file(file_path, Syntax::Typescript(Default::default()))?;
+
+    Ok(analyze_barrel_file(&ast, file_path).map_err(|violation| {
+        BarrelPluginError::InvalidBarrelFile {
+            barrel_file: file_path.to_string(),
+            error: violation.error,
+            location: source_location(&cm, file_path, violation.span),
+        }
+    }))
+}
+
+/// Looks up the analysis of a barrel file in the cache, analyzing it on a miss
+fn cached_barrel_analysis(
+    file_path: &str,
+    cache_dir: Option<&str>,
+) -> Result<BarrelAnalysis, BarrelPluginError> {
+    // Stamped before parsing, so edits made while parsing invalidate the entry
+    let stamp = FileStamp::of(file_path);
+
+    if let Some(analysis) = stamp
+        .as_ref()
+        .and_then(|stamp| get_analysis(file_path, stamp, cache_dir))
+    {
+        return Ok(analysis);
     }
+
+    let analysis = analyze_barrel(file_path)?;
+
+    if let Some(stamp) = stamp {
+        insert_analysis(file_path, stamp, analysis.clone(), cache_dir);
+    }
+
+    Ok(analysis)
 }
 
 /// Analyzes a barrel file and extracts re-export information
 ///
+/// Barrel files listed in the manifest and unchanged since it was generated are not parsed.
+///
 /// # Arguments
 ///
 /// * `file_path` - The path to the barrel file
-/// * `config` - The plugin configuration
+/// * `options` - The settings for the barrel file
+/// * `reader` - Sets the manifest and cache directory for the analysis of the barrel file
+/// * `issues` - Collects the warning and the reason for skipping an invalid barrel file
 ///
 /// # Returns
 ///
 /// A list of re-exports if the file is a valid barrel file, `Err` otherwise
 fn parse_barrel_file_exports(
     file_path: &str,
-    config: &Config,
-) -> Result<Option<Vec<ReExport>>, String> {
-    if let Ok(cache) = BARREL_CACHE.lock() {
-        if let Some(cached_exports) = cache.get(file_path) {
-            return Ok(cached_exports.clone());
-        }
-    }
+    options: &BarrelOptions,
+    reader: &BarrelReader,
+    issues: &mut ImportIssues,
+) -> Result<Option<Vec<ReExport>>, BarrelPluginError> {
+    let manifest_re_exports = reader
+        .manifest
+        .and_then(|manifest| manifest.re_exports(file_path));
 
-    let ast = parse_file(file_path)?;
+    let analysis = match manifest_re_exports {
+        Some(re_exports) => Ok(re_exports),
+        None => cached_barrel_analysis(file_path, reader.cache_dir)?,
+    };
 
-    match analyze_barrel_file(&ast, file_path) {
+    match analysis {
         Ok(re_exports) => {
             if re_exports.is_empty() {
-                return Err(format!(
-                    "E_UNRESOLVED_EXPORTS: No re-exports found in barrel file: {}",
-                    file_path
-                ));
-            }
-
-            if let Ok(mut cache) = BARREL_CACHE.lock() {
-                cache.insert(file_path.to_string(), Some(re_exports.clone()));
+                return Err(BarrelPluginError::NoReExports {
+                    barrel_file: file_path.to_string(),
+                });
             }
 
             Ok(Some(re_exports))
         }
-        Err(e) => {
-            let error_msg = format!(
-                "E_INVALID_BARREL_FILE: Invalid barrel file {}: {}",
-                file_path, e
-            );
+        Err(error) => match options.invalid_barrel_mode {
+            InvalidBarrelMode::Error => Err(error),
+            InvalidBarrelMode::Warn => {
+                issues.skip(error, true);
+                Ok(None)
+            }
+            InvalidBarrelMode::Off => {
+                issues.skip(error, false);
+                Ok(None)
+            }
+        },
+    }
+}
 
-            match config.invalid_barrel_mode {
-                InvalidBarrelMode::Error => Err(error_msg),
-                InvalidBarrelMode::Warn => {
-                    eprintln!("Warning: {}", error_msg);
-                    if let Ok(mut cache) = BARREL_CACHE.lock() {
-                        cache.insert(file_path.to_string(), None);
-                    }
-                    Ok(None)
-                }
-                InvalidBarrelMode::Off => {
-                    if let Ok(mut cache) = BARREL_CACHE.lock() {
-                        cache.insert(file_path.to_string(), None);
-                    }
-                    Ok(None)
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_core::common::FileName;
+    use swc_core::ecma::ast::{ModuleDecl, ModuleItem};
+
+    #[test]
+    fn test_rewrite_extension() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-extension-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(dir.join("ui")).unwrap();
+        std::fs::write(dir.join("ui/index.ts"), "").unwrap();
+        std::fs::write(dir.join("styles.css"), "").unwrap();
+        let dir = dir.to_string_lossy().to_string();
+
+        let button_ts = format!("{}/Button.ts", dir);
+        let button = format!("{}/Button", dir);
+        let styles = format!("{}/styles.css", dir);
+        let ui = format!("{}/ui", dir);
+
+        // Preserve
+        assert_eq!(
+            rewrite_extension(&button_ts, &OutputExtension::Preserve),
+            button_ts
+        );
+
+        // Strip
+        assert_eq!(
+            rewrite_extension(&button_ts, &OutputExtension::Strip),
+            button
+        );
+        assert_eq!(rewrite_extension(&button, &OutputExtension::Strip), button);
+        assert_eq!(rewrite_extension(&styles, &OutputExtension::Strip), styles);
+
+        // Js and mjs
+        assert_eq!(
+            rewrite_extension(&button_ts, &OutputExtension::Js),
+            format!("{}/Button.js", dir)
+        );
+        assert_eq!(
+            rewrite_extension(&button, &OutputExtension::Mjs),
+            format!("{}/Button.mjs", dir)
+        );
+        assert_eq!(
+            rewrite_extension(&ui, &OutputExtension::Js),
+            format!("{}/ui/index.js", dir)
+        );
+        assert_eq!(rewrite_extension(&styles, &OutputExtension::Js), styles);
+        assert_eq!(
+            rewrite_extension(&format!("{}/user.selectors", dir), &OutputExtension::Js),
+            format!("{}/user.selectors.js", dir)
+        );
+
+        // Mapping
+        let mapping = OutputExtension::Mapping(HashMap::from([
+            ("ts".to_string(), "js".to_string()),
+            ("tsx".to_string(), "".to_string()),
+        ]));
+        assert_eq!(
+            rewrite_extension(&button_ts, &mapping),
+            format!("{}/Button.js", dir)
+        );
+        assert_eq!(
+            rewrite_extension(&format!("{}/Button.tsx", dir), &mapping),
+            button
+        );
+        assert_eq!(rewrite_extension(&button, &mapping), button);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_import() {
+        let format = |code: &str| {
+            let cm: Lrc<SourceMap> = Default::default();
+            let fm = cm.new_source_file(Lrc::new(FileName::Anon), code.to_string());
+            let module = parse_file_as_module(
+                &fm,
+                Syntax::Typescript(Default::default()),
+                Default::default(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+
+            match &module.body[0] {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+                    format_import(import_decl)
                 }
+                _ => unreachable!(),
             }
+        };
+
+        assert_eq!(
+            format("import { A, B as C } from './ui';"),
+            r#"import { A, B as C } from "./ui""#
+        );
+        assert_eq!(
+            format("import Card, { type CardProps, \"a-b\" as ab } from './ui/Card';"),
+            r#"import Card, { type CardProps, "a-b" as ab } from "./ui/Card""#
+        );
+        assert_eq!(
+            format("import type { A } from './ui';"),
+            r#"import type { A } from "./ui""#
+        );
+    }
+
+    #[test]
+    fn test_unresolved_export_hints() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-hints-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(dir.join("forms")).unwrap();
+        std::fs::write(
+            dir.join("index.ts"),
+            "export { Button } from './Button';\nexport type { ButtonProps } from './Button';\nexport { Input } from './forms';",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("forms/index.ts"),
+            "export { Input } from './Input';\nexport { Select } from './Select';",
+        )
+        .unwrap();
+        let dir = dir.to_string_lossy().to_string();
+
+        let barrel_file = format!("{}/index.ts", dir);
+        let reader = BarrelReader {
+            match_barrel: &|_| None,
+            cache_dir: None,
+            manifest: None,
+        };
+        let re_exports = parse_barrel_file_exports(
+            &barrel_file,
+            &BarrelOptions::default(),
+            &reader,
+            &mut ImportIssues::default(),
+        )
+        .unwrap()
+        .unwrap();
+        let hints = |names: &[&str]| {
+            let names: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+            unresolved_export_hints(&barrel_file, &re_exports, &names, &reader)
+        };
+
+        // Typos and wrong casing
+        assert_eq!(
+            hints(&["Buton"]),
+            vec!["Did you mean `Button` instead of `Buton`?"]
+        );
+        assert_eq!(
+            hints(&["buttonProps"]),
+            vec!["Did you mean `ButtonProps` (type-only export) instead of `buttonProps`?"]
+        );
+
+        // Names only exported by a nested barrel file
+        assert_eq!(
+            hints(&["Select"]),
+            vec![format!(
+                "`Select` is exported by the nested barrel file {}/forms/index.ts, but is not re-exported by {}",
+                dir, barrel_file
+            )]
+        );
+
+        // No hints for unrelated names and default imports
+        assert!(hints(&["Action", "default"]).is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_transform_default_imports() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-defaults-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let dir = dir.to_string_lossy().to_string();
+
+        let transform = |barrel: &str, code: &str| {
+            let barrel_file = format!("{}/{}.ts", dir, barrel);
+            let cm: Lrc<SourceMap> = Default::default();
+            let fm = cm.new_source_file(Lrc::new(FileName::Anon), code.to_string());
+            let module = parse_file_as_module(
+                &fm,
+                Syntax::Typescript(Default::default()),
+                Default::default(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+            let ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) = &module.body[0] else {
+                unreachable!()
+            };
+
+            transform_import(
+                import_decl,
+                &barrel_file,
+                &BarrelOptions::default(),
+                &OutputExtension::Preserve,
+                &BarrelReader {
+                    match_barrel: &|_| None,
+                    cache_dir: None,
+                    manifest: None,
+                },
+                &|path| Ok(path.replacen(&dir, ".", 1)),
+                &mut ImportIssues::default(),
+            )
+            .map(|imports| {
+                imports
+                    .unwrap()
+                    .iter()
+                    .map(format_import)
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            })
+        };
+
+        // Default export of the barrel file from the default export of a module
+        std::fs::write(
+            format!("{}/default.ts", dir),
+            r#"export { default } from "./ui/Button";"#,
+        )
+        .unwrap();
+        assert_eq!(
+            transform("default", "import Button from './default';"),
+            Ok(r#"import Button from "./ui/Button""#.into())
+        );
+        assert_eq!(
+            transform("default", "import { default as Button } from './default';"),
+            Ok(r#"import Button from "./ui/Button""#.into())
+        );
+
+        // Named exports of the barrel file from the default export of a module
+        std::fs::write(
+            format!("{}/named.ts", dir),
+            r#"export { default as Card } from "./ui/Card";
+export { default as "card-list" } from "./ui/CardList";"#,
+        )
+        .unwrap();
+        assert_eq!(
+            transform(
+                "named",
+                "import { Card, \"card-list\" as List } from './named';"
+            ),
+            Ok(r#"import Card from "./ui/Card"; import List from "./ui/CardList""#.into())
+        );
+        assert_eq!(
+            transform("named", "import { type Card as Props } from './named';"),
+            Ok(r#"import { type default as Props } from "./ui/Card""#.into())
+        );
+        assert!(matches!(
+            transform("named", "import Card from './named';"),
+            Err(BarrelPluginError::UnresolvedExports { names, .. }) if names == ["default"]
+        ));
+
+        // Default export of the barrel file from a named export of a module
+        std::fs::write(
+            format!("{}/renamed.ts", dir),
+            r#"export { Input as default } from "./ui/Input";"#,
+        )
+        .unwrap();
+        assert_eq!(
+            transform("renamed", "import Input from './renamed';"),
+            Ok(r#"import { Input } from "./ui/Input""#.into())
+        );
+        assert_eq!(
+            transform("renamed", "import { default as Field } from './renamed';"),
+            Ok(r#"import { Input as Field } from "./ui/Input""#.into())
+        );
+
+        // Exports named by string literals
+        std::fs::write(
+            format!("{}/strings.ts", dir),
+            r#"export { Link as "nav-link", "icon-name" as Icon, "a-b" } from "./ui/names";"#,
+        )
+        .unwrap();
+        assert_eq!(
+            transform(
+                "strings",
+                "import { \"nav-link\" as NavLink, Icon, \"a-b\" as ab } from './strings';"
+            ),
+            Ok(
+                r#"import { Link as NavLink, "icon-name" as Icon, "a-b" as ab } from "./ui/names""#
+                    .into()
+            )
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_analyze_barrel_as_typescript() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-syntax-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let barrel_file = dir.join("index.js").to_string_lossy().to_string();
+
+        // Barrel files are parsed as TypeScript whatever their extension
+        std::fs::write(
+            &barrel_file,
+            "export type { ButtonProps } from './ui/Button';\nexport { Button } from './ui/Button';",
+        )
+        .unwrap();
+        let re_exports = analyze_barrel(&barrel_file).unwrap().unwrap();
+        assert_eq!(re_exports.len(), 2);
+        assert!(re_exports[0].is_type_only);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_verify_target() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-verify-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(dir.join("ui")).unwrap();
+        std::fs::write(
+            dir.join("ui/Button.tsx"),
+            "export const Button = () => <button />;\nexport type ButtonProps = {};",
+        )
+        .unwrap();
+        std::fs::write(dir.join("ui/legacy.js"), "module.exports = { Legacy: 1 };").unwrap();
+        let dir = dir.to_string_lossy().to_string();
+        let barrel_file = format!("{}/index.ts", dir);
+
+        let re_export = |original_name: &str, source_path: &str| ReExport {
+            exported_name: original_name.to_string(),
+            source_path: source_path.to_string(),
+            original_name: original_name.to_string(),
+            is_default: original_name == "default",
+            is_type_only: false,
+        };
+        let verify =
+            |re_export: &ReExport, mode| verify_target(&barrel_file, &dir, re_export, mode);
+
+        // Existing targets, including TypeScript sources of emitted scripts
+        for mode in [VerifyTargetsMode::Exists, VerifyTargetsMode::Exports] {
+            assert_eq!(verify(&re_export("Button", "./ui/Button"), mode), Ok(()));
+            assert_eq!(
+                verify(&re_export("ButtonProps", "./ui/Button.js"), mode),
+                Ok(())
+            );
         }
+
+        // Missing targets
+        assert_eq!(
+            verify(&re_export("Input", "./ui/Input"), VerifyTargetsMode::Exists),
+            Err(BarrelPluginError::MissingTarget {
+                barrel_file: barrel_file.clone(),
+                name: "Input".into(),
+                target: format!("{}/ui/Input", dir),
+            })
+        );
+        assert_eq!(
+            verify(&re_export("Input", "./ui/Input"), VerifyTargetsMode::Off),
+            Ok(())
+        );
+
+        // Names not exported by the target, only checked in exports mode
+        assert_eq!(
+            verify(
+                &re_export("Buton", "./ui/Button"),
+                VerifyTargetsMode::Exists
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            verify(
+                &re_export("Buton", "./ui/Button"),
+                VerifyTargetsMode::Exports
+            ),
+            Err(BarrelPluginError::TargetExportNotFound {
+                barrel_file: barrel_file.clone(),
+                name: "Buton".into(),
+                target: format!("{}/ui/Button.tsx", dir),
+                original_name: "Buton".into(),
+                hints: vec![format!("{}/ui/Button.tsx exports `Button` instead", dir)],
+            })
+        );
+        assert!(matches!(
+            verify(
+                &re_export("default", "./ui/Button"),
+                VerifyTargetsMode::Exports
+            ),
+            Err(BarrelPluginError::TargetExportNotFound { .. })
+        ));
+
+        // Targets without static ESM exports and packages are not verified
+        assert_eq!(
+            verify(
+                &re_export("Legacy", "./ui/legacy"),
+                VerifyTargetsMode::Exports
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            verify(&re_export("Link", "router"), VerifyTargetsMode::Exports),
+            Ok(())
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
     }
 }
@@ -2,27 +2,59 @@
 //!
 //! This plugin transforms imports from barrel files (index.ts) into direct imports
 //! from the source files. This helps to avoid circular dependencies and improves tree-shaking.
+//!
+//! Besides the WASM plugin, the transform can be embedded natively in Rust tools using
+//! `swc_core`: [`barrel_files_pass`] creates it as an SWC pass. Disable the default `plugin`
+//! feature to build the crate without the plugin proxies.
 
 mod alias_resolver;
+mod cache;
+mod codemod;
+mod compiled_config;
 mod config;
+mod errors;
+mod glob;
+mod import_merger;
 mod import_transformer;
+mod manifest;
 mod path_resolver;
 mod paths;
 mod pattern_matcher;
 mod re_export;
+mod report;
+mod suggestions;
+mod validation;
 mod visitor;
 
-use swc_core::ecma::ast::Program;
+use swc_core::common::comments::Comments;
+use swc_core::ecma::ast::Pass;
 use swc_core::ecma::visit::visit_mut_pass;
-use swc_core::plugin::metadata::TransformPluginMetadataContextKind;
-use swc_core::plugin::{plugin_transform, proxies::TransformPluginProgramMetadata};
+#[cfg(feature = "plugin")]
+use swc_core::{
+    ecma::ast::Program,
+    plugin::metadata::TransformPluginMetadataContextKind,
+    plugin::{plugin_transform, proxies::TransformPluginProgramMetadata},
+};
 
-use config::Config;
+pub use codemod::rewrite_source;
+pub use config::{
+    Alias, BarrelOptions, BarrelPattern, Config, DeepImportMode, DiagnosticsOutput,
+    InvalidBarrelMode, MissingBarrelMode, OutputExtension, OutputPathStyle, SelfImportMode,
+    TransformMode, UnsupportedImportMode, VerifyTargetsMode,
+};
+pub use errors::{BarrelPluginError, SourceLocation};
+pub use manifest::{analyze_barrel_files, generate_manifest, Manifest, ManifestEntry};
+pub use re_export::{BarrelError, ReExport};
+pub use validation::{validate_barrel, validate_barrel_files, Violation};
+// Used by the pattern matching benchmark
+#[doc(hidden)]
+pub use pattern_matcher::{CompiledPattern, PatternIndex};
 use visitor::BarrelTransformVisitor;
 
 /// SWC plugin transform entry point
 ///
 /// This function is called by SWC to transform the AST.
+#[cfg(feature = "plugin")]
 #[plugin_transform]
 pub fn process_transform(program: Program, metadata: TransformPluginProgramMetadata) -> Program {
     let cwd = metadata
@@ -40,11 +72,181 @@ pub fn process_transform(program: Program, metadata: TransformPluginProgramMetad
     )
     .expect("E_INVALID_CONFIG: Error parsing barrel plugin configuration");
 
-    let visitor =
-        BarrelTransformVisitor::new(&config, cwd, filename).expect("Error creating visitor");
+    let visitor = BarrelTransformVisitor::new(&config, cwd, filename, metadata.comments)
+        .unwrap_or_else(|error| panic!("Error creating visitor: {}", error));
 
     match visitor {
         Some(visitor) => program.apply(visit_mut_pass(visitor)),
         None => program,
     }
 }
+
+/// Creates the barrel files transform as an SWC pass for native embedders
+///
+/// Unlike the WASM plugin, the pass reads the real filesystem, so source and barrel files
+/// outside of `cwd` are supported. Diagnostics are reported through the SWC `HANDLER`, which
+/// must be set while the pass runs, as it is by the SWC compiler.
+///
+/// # Arguments
+///
+/// * `config` - The plugin configuration
+/// * `cwd` - The absolute path of the directory the configuration is relative to
+/// * `filename` - The path to the transformed file, absolute or relative to `cwd`
+/// * `comments` - The comments of the file, to read the pragma comments disabling the transform
+///
+/// # Returns
+///
+/// The pass, which leaves files excluded by the configuration unchanged, or `Err` if the
+/// configuration is invalid
+pub fn barrel_files_pass<C: Comments>(
+    config: &Config,
+    cwd: &str,
+    filename: &str,
+    comments: Option<C>,
+) -> Result<impl Pass, BarrelPluginError> {
+    let visitor = BarrelTransformVisitor::with_fs_root(
+        config,
+        cwd.to_string(),
+        cwd,
+        filename.to_string(),
+        comments,
+    )?;
+
+    Ok(visitor.map(visit_mut_pass))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use swc_core::common::comments::SingleThreadedComments;
+    use swc_core::common::errors::{ColorConfig, Handler, HANDLER};
+    use swc_core::common::sync::Lrc;
+    use swc_core::common::{FileName, SourceMap};
+    use swc_core::ecma::ast::{ModuleDecl, ModuleItem, Program};
+    use swc_core::ecma::parser::{parse_file_as_module, Syntax};
+
+    #[test]
+    fn test_barrel_files_pass() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-pass-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("project/src/features/user")).unwrap();
+        fs::create_dir_all(dir.join("shared/ui")).unwrap();
+        fs::write(
+            dir.join("project/src/features/user/index.ts"),
+            "export { UserCard } from './ui/UserCard';",
+        )
+        .unwrap();
+        // Outside of cwd, which the WASM plugin cannot read
+        fs::write(
+            dir.join("shared/ui/index.ts"),
+            "export { Button } from './Button';",
+        )
+        .unwrap();
+        let cwd = dir.join("project").to_string_lossy().to_string();
+
+        let config: Config = serde_json::from_str(
+            r##"{
+                "aliases": [
+                    { "pattern": "#features/*", "paths": ["src/features/*/index.ts"] },
+                    { "pattern": "#ui", "paths": ["../shared/ui/index.ts"] }
+                ],
+                "patterns": ["src/features/*/index.ts", "../shared/ui/index.ts"]
+            }"##,
+        )
+        .unwrap();
+
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "import { UserCard } from '#features/user'; import { Button } from '#ui';",
+        );
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::Typescript(Default::default()),
+            Default::default(),
+            None,
+            &mut vec![],
+        )
+        .unwrap();
+
+        let pass =
+            barrel_files_pass::<SingleThreadedComments>(&config, &cwd, "src/pages/home.ts", None)
+                .unwrap();
+
+        let handler = Handler::with_tty_emitter(ColorConfig::Never, true, false, Some(cm));
+        let program = HANDLER.set(&handler, || Program::Module(module).apply(pass));
+        assert!(!handler.has_errors());
+
+        let sources: Vec<String> = program
+            .expect_module()
+            .body
+            .into_iter()
+            .filter_map(|item| match item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    Some(import.src.value.to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            sources,
+            vec!["../features/user/ui/UserCard", "../../../shared/ui/Button"]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_config_without_json() {
+        let config = Config {
+            patterns: vec![
+                BarrelPattern::from("src/features/*/index.ts"),
+                BarrelPattern {
+                    invalid_barrel_mode: Some(InvalidBarrelMode::Warn),
+                    output: Some(OutputPathStyle::Alias),
+                    ..BarrelPattern::from("src/shared/*/index.ts")
+                },
+            ],
+            aliases: Some(vec![Alias {
+                pattern: "#features/*".to_string(),
+                paths: vec!["src/features/*/index.ts".to_string()],
+                context: None,
+            }]),
+            missing_barrel_mode: MissingBarrelMode::Warn,
+            output_extension: OutputExtension::Js,
+            ..Default::default()
+        };
+
+        let json_config: Config = serde_json::from_str(
+            r##"{
+                "patterns": [
+                    "src/features/*/index.ts",
+                    {
+                        "pattern": "src/shared/*/index.ts",
+                        "invalid_barrel_mode": "warn",
+                        "output": "alias"
+                    }
+                ],
+                "aliases": [
+                    { "pattern": "#features/*", "paths": ["src/features/*/index.ts"] }
+                ],
+                "missing_barrel_mode": "warn",
+                "output_extension": "js"
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(config, json_config);
+
+        assert!(barrel_files_pass::<SingleThreadedComments>(
+            &config,
+            "/project",
+            "src/pages/home.ts",
+            None
+        )
+        .is_ok());
+    }
+}
@@ -0,0 +1,472 @@
+//! Manifest module for the barrel files plugin
+//!
+//! A manifest maps barrel files to their re-exports. It is generated once ahead of the
+//! build from the configured patterns, so the plugin does not need to read and parse the
+//! barrel files in every worker. Each entry is stamped with the metadata of the barrel file,
+//! so barrel files changed since the manifest was generated are parsed again.
+
+use crate::cache::FileStamp;
+use crate::config::Config;
+use crate::errors::BarrelPluginError;
+use crate::import_transformer::analyze_barrel;
+use crate::paths::path_join;
+use crate::pattern_matcher::CompiledPattern;
+use crate::re_export::ReExport;
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+/// Manifests loaded for the current build by their virtual path
+static LOADED_MANIFESTS: Lazy<Mutex<HashMap<String, Arc<LoadedManifest>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Re-exports of barrel files, serialized as an object keyed by the path of the barrel
+/// file relative to the current working directory
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Manifest {
+    /// Entries by barrel file path
+    pub barrels: BTreeMap<String, ManifestEntry>,
+}
+
+/// Re-exports of a barrel file in a manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Stamp of the barrel file when it was analyzed
+    stamp: FileStamp,
+
+    /// Re-exports of the barrel file
+    pub re_exports: Vec<ReExport>,
+}
+
+/// Manifest with its barrel file paths resolved to virtual paths
+#[derive(Debug)]
+pub struct LoadedManifest {
+    /// Stamp of the manifest file when it was loaded
+    stamp: FileStamp,
+
+    /// Entries by virtual barrel file path
+    barrels: HashMap<String, ManifestEntry>,
+}
+
+impl LoadedManifest {
+    /// Looks up the re-exports of a barrel file
+    ///
+    /// # Returns
+    ///
+    /// The re-exports if the barrel file is listed in the manifest and unchanged since the
+    /// manifest was generated, `None` otherwise
+    pub fn re_exports(&self, barrel_file: &str) -> Option<Vec<ReExport>> {
+        let entry = self.barrels.get(barrel_file)?;
+
+        (FileStamp::of(barrel_file)? == entry.stamp).then(|| entry.re_exports.clone())
+    }
+}
+
+/// Generates a manifest for the barrel files matched by the patterns of a configuration
+///
+/// Invalid barrel files are left out of the manifest, so they are analyzed and reported
+/// by the plugin during the build.
+///
+/// # Arguments
+///
+/// * `config` - The plugin configuration
+/// * `cwd` - The current working directory the patterns are relative to
+///
+/// # Returns
+///
+/// The manifest of all valid barrel files, `Err` if a pattern cannot be compiled
+pub fn generate_manifest(config: &Config, cwd: &str) -> Result<Manifest, BarrelPluginError> {
+    let mut barrels = BTreeMap::new();
+
+    for file in find_barrel_files(config, cwd)? {
+        // Stamped before parsing, so edits made while parsing invalidate the entry
+        let Some(stamp) = FileStamp::of(&file) else {
+            continue;
+        };
+
+        if let Ok(Ok(re_exports)) = analyze_barrel(&file) {
+            if !re_exports.is_empty() {
+                barrels.insert(relative_to(cwd, &file), ManifestEntry { stamp, re_exports });
+            }
+        }
+    }
+
+    Ok(Manifest { barrels })
+}
+
+/// Analyzes the barrel files matched by the patterns of a configuration
+///
+/// # Arguments
+///
+/// * `config` - The plugin configuration
+/// * `cwd` - The current working directory the patterns are relative to
+///
+/// # Returns
+///
+/// The re-exports of each barrel file by its path relative to `cwd`, or the error making
+/// it an invalid barrel file. `Err` if a pattern cannot be compiled.
+pub fn analyze_barrel_files(
+    config: &Config,
+    cwd: &str,
+) -> Result<BTreeMap<String, Result<Vec<ReExport>, BarrelPluginError>>, BarrelPluginError> {
+    let mut barrels = BTreeMap::new();
+
+    for file in find_barrel_files(config, cwd)? {
+        let analysis = match analyze_barrel(&file) {
+            Ok(Ok(re_exports)) if re_exports.is_empty() => Err(BarrelPluginError::NoReExports {
+                barrel_file: file.clone(),
+            }),
+            Ok(analysis) => analysis,
+            Err(error) => Err(error),
+        };
+
+        barrels.insert(relative_to(cwd, &file), analysis);
+    }
+
+    Ok(barrels)
+}
+
+/// Finds the barrel files matched by the patterns of a configuration
+///
+/// # Arguments
+///
+/// * `config` - The plugin configuration
+/// * `cwd` - The current working directory the patterns are relative to
+///
+/// # Returns
+///
+/// The absolute paths of the barrel files, `Err` if a pattern cannot be compiled
+pub fn find_barrel_files(config: &Config, cwd: &str) -> Result<Vec<String>, BarrelPluginError> {
+    let mut barrel_files = Vec::new();
+
+    for pattern in &config.patterns {
+        let pattern_path = path_join(cwd, &pattern.pattern);
+        let compiled_pattern = CompiledPattern::new(&pattern_path).map_err(|reason| {
+            BarrelPluginError::InvalidPattern {
+                pattern: pattern_path.clone(),
+                reason,
+            }
+        })?;
+
+        // Wildcards never match `/`, so only the directories between the literal prefix
+        // and the end of the pattern need to be listed
+        let literal_prefix = compiled_pattern.literal_prefix();
+        let root = &literal_prefix[..literal_prefix.rfind('/').unwrap_or_default()];
+        let depth = pattern_path[root.len()..].matches('/').count();
+
+        let mut files = Vec::new();
+        collect_files(root, depth.saturating_sub(1), &mut files);
+
+        barrel_files.extend(
+            files
+                .into_iter()
+                .filter(|file| compiled_pattern.matches(file)),
+        );
+    }
+
+    barrel_files.sort();
+    barrel_files.dedup();
+
+    Ok(barrel_files)
+}
+
+/// Converts the absolute path of a barrel file to a path relative to cwd
+///
+/// Barrel files outside of cwd keep their absolute path.
+pub fn relative_to(cwd: &str, file: &str) -> String {
+    match Path::new(file).strip_prefix(cwd) {
+        Ok(relative_path) => relative_path.to_string_lossy().to_string(),
+        Err(_) => file.to_string(),
+    }
+}
+
+/// Lists the files in a directory and its subdirectories up to the given depth
+fn collect_files(dir: &str, depth: usize, files: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = format!("{}/{}", dir, entry.file_name().to_string_lossy());
+
+        // Follows symlinks, e.g. to workspace packages
+        match fs::metadata(&path) {
+            Ok(metadata) if metadata.is_file() => files.push(path),
+            Ok(metadata) if metadata.is_dir() && depth > 0 => {
+                collect_files(&path, depth - 1, files)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reads a manifest file and resolves its barrel file paths
+///
+/// # Arguments
+///
+/// * `path` - The virtual path of the manifest file
+/// * `to_virtual_path` - Resolves a barrel file path relative to cwd to a virtual path
+///
+/// # Returns
+///
+/// The entries by virtual barrel file path, `Err` if the manifest cannot be read. Barrel
+/// files that cannot be resolved, e.g. outside of cwd in the WebAssembly plugin, are left
+/// out, as the plugin cannot read them either.
+fn read_manifest(
+    path: &str,
+    to_virtual_path: &dyn Fn(&str) -> Result<String, BarrelPluginError>,
+) -> Result<HashMap<String, ManifestEntry>, BarrelPluginError> {
+    let content = fs::read_to_string(path).map_err(|e| BarrelPluginError::InvalidManifest {
+        path: path.to_string(),
+        reason: e.to_string(),
+    })?;
+
+    let manifest: Manifest =
+        serde_json::from_str(&content).map_err(|e| BarrelPluginError::InvalidManifest {
+            path: path.to_string(),
+            reason: e.to_string(),
+        })?;
+
+    Ok(manifest
+        .barrels
+        .into_iter()
+        .filter_map(|(barrel_file, entry)| Some((to_virtual_path(&barrel_file).ok()?, entry)))
+        .collect())
+}
+
+/// Loads a manifest, reusing the manifest loaded for the same path
+///
+/// The manifest is only read again when the manifest file changes.
+///
+/// # Arguments
+///
+/// * `path` - The virtual path of the manifest file
+/// * `to_virtual_path` - Resolves a barrel file path relative to cwd to a virtual path
+///
+/// # Returns
+///
+/// The loaded manifest, `Err` if the manifest cannot be read
+pub fn load_manifest(
+    path: &str,
+    to_virtual_path: &dyn Fn(&str) -> Result<String, BarrelPluginError>,
+) -> Result<Arc<LoadedManifest>, BarrelPluginError> {
+    let stamp = FileStamp::of(path).ok_or_else(|| BarrelPluginError::InvalidManifest {
+        path: path.to_string(),
+        reason: "File not found".into(),
+    })?;
+
+    let loaded_manifest = LOADED_MANIFESTS
+        .lock()
+        .ok()
+        .and_then(|loaded_manifests| loaded_manifests.get(path).cloned());
+    if let Some(manifest) = loaded_manifest {
+        if manifest.stamp == stamp {
+            return Ok(manifest);
+        }
+    }
+
+    let manifest = Arc::new(LoadedManifest {
+        stamp,
+        barrels: read_manifest(path, to_virtual_path)?,
+    });
+
+    if let Ok(mut loaded_manifests) = LOADED_MANIFESTS.lock() {
+        loaded_manifests.insert(path.to_string(), manifest.clone());
+    }
+
+    Ok(manifest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_manifest() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-manifest-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src/features/user/ui")).unwrap();
+        fs::create_dir_all(dir.join("src/features/cart")).unwrap();
+        fs::create_dir_all(dir.join("src/features/nested/deep")).unwrap();
+        fs::write(
+            dir.join("src/features/user/index.ts"),
+            "export { UserCard } from './ui/UserCard';",
+        )
+        .unwrap();
+        fs::write(dir.join("src/features/user/ui/index.ts"), "").unwrap();
+        fs::write(
+            dir.join("src/features/cart/index.ts"),
+            "export * from './ui';",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("src/features/nested/deep/index.ts"),
+            "export { Deep } from './Deep';",
+        )
+        .unwrap();
+        let cwd = dir.to_string_lossy().to_string();
+
+        let config: Config =
+            serde_json::from_str(r#"{ "patterns": ["src/features/*/index.ts"] }"#).unwrap();
+        let barrels = analyze_barrel_files(&config, &cwd).unwrap();
+        assert_eq!(
+            barrels.keys().collect::<Vec<_>>(),
+            vec!["src/features/cart/index.ts", "src/features/user/index.ts"]
+        );
+        assert!(matches!(
+            barrels["src/features/cart/index.ts"],
+            Err(BarrelPluginError::InvalidBarrelFile { .. })
+        ));
+
+        let manifest = generate_manifest(&config, &cwd).unwrap();
+        let user_barrel = format!("{}/src/features/user/index.ts", cwd);
+        let user_re_exports = vec![ReExport {
+            exported_name: "UserCard".into(),
+            source_path: "./ui/UserCard".into(),
+            original_name: "UserCard".into(),
+            is_default: false,
+            is_type_only: false,
+        }];
+
+        // Invalid barrel files and files not matched by the patterns are left out
+        assert_eq!(
+            manifest,
+            Manifest {
+                barrels: BTreeMap::from([(
+                    "src/features/user/index.ts".to_string(),
+                    ManifestEntry {
+                        stamp: FileStamp::of(&user_barrel).unwrap(),
+                        re_exports: user_re_exports.clone(),
+                    }
+                )]),
+            }
+        );
+
+        let manifest_path = dir.join("manifest.json").to_string_lossy().to_string();
+        fs::write(&manifest_path, serde_json::to_string(&manifest).unwrap()).unwrap();
+
+        let loaded_manifest = load_manifest(&manifest_path, &|barrel_file| {
+            Ok(path_join(&cwd, barrel_file))
+        })
+        .unwrap();
+        assert_eq!(
+            loaded_manifest.re_exports(&user_barrel),
+            Some(user_re_exports)
+        );
+
+        // Loaded once per manifest path
+        let reloaded_manifest =
+            load_manifest(&manifest_path, &|_| unreachable!("manifest read again")).unwrap();
+        assert!(Arc::ptr_eq(&loaded_manifest, &reloaded_manifest));
+
+        // Barrel files changed since the manifest was generated are parsed again
+        fs::write(
+            &user_barrel,
+            "export { UserCard, UserAvatar } from './ui/UserCard';",
+        )
+        .unwrap();
+        assert_eq!(loaded_manifest.re_exports(&user_barrel), None);
+
+        fs::write(&manifest_path, "[]").unwrap();
+        assert!(matches!(
+            read_manifest(&manifest_path, &|barrel_file| Ok(barrel_file.to_string())),
+            Err(BarrelPluginError::InvalidManifest { .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_relative_to() {
+        assert_eq!(
+            relative_to("/a/proj", "/a/proj/src/index.ts"),
+            "src/index.ts"
+        );
+        assert_eq!(
+            relative_to("/a/proj/", "/a/proj/src/index.ts"),
+            "src/index.ts"
+        );
+        assert_eq!(
+            relative_to("/a/proj", "/a/project2/x/index.ts"),
+            "/a/project2/x/index.ts"
+        );
+        assert_eq!(
+            relative_to("/a/proj", "/a/shared/ui/index.ts"),
+            "/a/shared/ui/index.ts"
+        );
+    }
+
+    #[test]
+    fn test_generate_manifest_outside_cwd() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-manifest-outside-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("proj/src/user")).unwrap();
+        fs::create_dir_all(dir.join("project2/src/cart")).unwrap();
+        fs::write(
+            dir.join("proj/src/user/index.ts"),
+            "export { UserCard } from './UserCard';",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("project2/src/cart/index.ts"),
+            "export { Cart } from './Cart';",
+        )
+        .unwrap();
+        let cwd = dir.join("proj").to_string_lossy().to_string();
+        let sibling_barrel = dir
+            .join("project2/src/cart/index.ts")
+            .to_string_lossy()
+            .to_string();
+
+        // The sibling directory shares the prefix of cwd but is outside of it
+        let config: Config = serde_json::from_str(
+            r#"{ "patterns": ["src/*/index.ts", "../project2/src/*/index.ts"] }"#,
+        )
+        .unwrap();
+        let manifest = generate_manifest(&config, &cwd).unwrap();
+        assert_eq!(
+            manifest.barrels.keys().collect::<Vec<_>>(),
+            vec![&sibling_barrel, "src/user/index.ts"]
+        );
+
+        let manifest_path = dir.join("manifest.json").to_string_lossy().to_string();
+        fs::write(&manifest_path, serde_json::to_string(&manifest).unwrap()).unwrap();
+
+        let loaded_manifest = load_manifest(&manifest_path, &|barrel_file| {
+            Ok(path_join(&cwd, barrel_file))
+        })
+        .unwrap();
+        assert!(loaded_manifest.re_exports(&sibling_barrel).is_some());
+        assert!(loaded_manifest
+            .re_exports(&format!("{}/src/user/index.ts", cwd))
+            .is_some());
+
+        // Barrel files the plugin cannot resolve are left out
+        let barrels = read_manifest(&manifest_path, &|barrel_file| {
+            if barrel_file.starts_with('/') {
+                Err(BarrelPluginError::InvalidFilePath {
+                    path: barrel_file.to_string(),
+                })
+            } else {
+                Ok(path_join(&cwd, barrel_file))
+            }
+        })
+        .unwrap();
+        assert_eq!(
+            barrels.keys().collect::<Vec<_>>(),
+            vec![&format!("{}/src/user/index.ts", cwd)]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,10 +1,11 @@
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::errors::BarrelPluginError;
 use crate::paths::{normalize_path, path_join};
 
 /// Virtual filesystem root directory
-const SWC_VIRTUAL_FS_ROOT_DIR: &str = "/cwd";
+pub const SWC_VIRTUAL_FS_ROOT_DIR: &str = "/cwd";
 
 /// Handles path resolution including symlink mappings
 #[derive(Clone)]
@@ -14,6 +15,10 @@ pub struct PathResolver {
 
     /// Map of external paths to internal symlinked paths
     symlinks: HashMap<String, String>,
+
+    /// Directory at which the current working directory is mounted in the filesystem
+    /// visible to the plugin
+    root: String,
 }
 
 impl PathResolver {
@@ -32,9 +37,19 @@ impl PathResolver {
         Self {
             cwd: cwd.into(),
             symlinks,
+            root: SWC_VIRTUAL_FS_ROOT_DIR.into(),
         }
     }
 
+    /// Mounts the current working directory at the given root instead of `/cwd`
+    ///
+    /// Native tools, which see the real filesystem, use the current working directory itself
+    /// as the root.
+    pub fn with_root(mut self, root: &str) -> Self {
+        self.root = root.into();
+        self
+    }
+
     /// Resolves a path, applying symlink mappings if applicable
     ///
     /// # Arguments
@@ -129,6 +144,22 @@ impl PathResolver {
         }
     }
 
+    /// Whether the whole filesystem is visible, as for native tools using the current
+    /// working directory as the root
+    fn is_native(&self) -> bool {
+        self.root == self.cwd
+    }
+
+    /// Checks whether a file can be read, as the WASM plugin can only access files in the
+    /// current working directory
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The absolute path to the file
+    pub fn is_accessible(&self, path: &str) -> bool {
+        self.is_native() || path.starts_with(&self.cwd)
+    }
+
     /// Resolves a path to a virtual path
     ///
     /// # Arguments
@@ -139,29 +170,60 @@ impl PathResolver {
     /// # Returns
     ///
     /// The resolved virtual path
-    pub fn to_virtual_path(&self, path: &str) -> Result<String, String> {
+    pub fn to_virtual_path(&self, path: &str) -> Result<String, BarrelPluginError> {
         // TODO: TEST THIS
-        if path.starts_with(SWC_VIRTUAL_FS_ROOT_DIR) {
+        if path.starts_with(&self.root) {
             return Ok(path.to_string());
         }
         // END TODO
 
         if path.starts_with(&self.cwd) {
             let without_cwd = &path[self.cwd.len() + 1..];
-            let result = path_join(SWC_VIRTUAL_FS_ROOT_DIR, without_cwd);
+            let result = path_join(&self.root, without_cwd);
             return Ok(result);
         }
 
-        if Path::new(&path).is_absolute() {
-            return Err(format!(
-                "E_INVALID_FILE_PATH: Absolute paths not starting with cwd are not supported: {}",
-                path
-            ));
+        if Path::new(&path).is_absolute() && !self.is_native() {
+            return Err(BarrelPluginError::InvalidFilePath {
+                path: path.to_string(),
+            });
         }
 
-        let result = path_join(SWC_VIRTUAL_FS_ROOT_DIR, path);
+        let result = path_join(&self.root, path);
         Ok(result)
     }
+
+    /// Converts a virtual path to an absolute path from the project root
+    ///
+    /// # Arguments
+    ///
+    /// * `virtual_path` - The virtual path to convert
+    ///
+    /// # Returns
+    ///
+    /// The path starting with `/` relative to the current working directory
+    pub fn to_root_path(&self, virtual_path: &str) -> String {
+        let relative_path = virtual_path
+            .strip_prefix(self.root.as_str())
+            .unwrap_or(virtual_path);
+
+        path_join("/", relative_path)
+    }
+
+    /// Converts a virtual path to a path relative to the current working directory
+    ///
+    /// # Arguments
+    ///
+    /// * `virtual_path` - The virtual path to convert
+    ///
+    /// # Returns
+    ///
+    /// The path relative to the current working directory, without a leading `./`
+    pub fn to_cwd_relative_path(&self, virtual_path: &str) -> String {
+        self.to_root_path(virtual_path)
+            .trim_start_matches('/')
+            .to_string()
+    }
 }
 
 #[cfg(test)]
@@ -361,11 +423,68 @@ mod tests {
         let path = "/other/path/file.rs";
         assert!(resolver.to_virtual_path(path).is_err());
         assert_eq!(
-            resolver.to_virtual_path(path).unwrap_err(),
+            resolver.to_virtual_path(path).unwrap_err().to_string(),
             "E_INVALID_FILE_PATH: Absolute paths not starting with cwd are not supported: /other/path/file.rs"
         );
     }
 
+    #[test]
+    fn test_to_root_path() {
+        let resolver = PathResolver::new(&None, "/home/user/project");
+
+        assert_eq!(
+            resolver.to_root_path("/cwd/src/modules/user/models/User"),
+            "/src/modules/user/models/User"
+        );
+        assert_eq!(resolver.to_root_path("/cwd"), "/");
+    }
+
+    #[test]
+    fn test_to_cwd_relative_path() {
+        let resolver = PathResolver::new(&None, "/home/user/project");
+
+        assert_eq!(
+            resolver.to_cwd_relative_path("/cwd/src/modules/user/index.ts"),
+            "src/modules/user/index.ts"
+        );
+    }
+
+    #[test]
+    fn test_native_root() {
+        let resolver =
+            PathResolver::new(&None, "/home/user/project").with_root("/home/user/project");
+
+        assert_eq!(
+            resolver
+                .to_virtual_path("/home/user/project/src/index.ts")
+                .unwrap(),
+            "/home/user/project/src/index.ts"
+        );
+        assert_eq!(
+            resolver.to_virtual_path("src/index.ts").unwrap(),
+            "/home/user/project/src/index.ts"
+        );
+        assert_eq!(
+            resolver.to_root_path("/home/user/project/src/index.ts"),
+            "/src/index.ts"
+        );
+        assert_eq!(
+            resolver.to_cwd_relative_path("/home/user/project/src/index.ts"),
+            "src/index.ts"
+        );
+
+        // The whole filesystem is visible to native tools
+        assert_eq!(
+            resolver
+                .to_virtual_path("/home/user/shared/index.ts")
+                .unwrap(),
+            "/home/user/shared/index.ts"
+        );
+        assert!(resolver.is_accessible("/home/user/shared/index.ts"));
+        assert!(!PathResolver::new(&None, "/home/user/project")
+            .is_accessible("/home/user/shared/index.ts"));
+    }
+
     #[test]
     fn test_to_virtual_path_already_virtual() {
         let resolver = PathResolver::new(&Some(HashMap::new()), "/home/user/project");
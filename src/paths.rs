@@ -2,17 +2,46 @@
 //!
 //! This module provides functionality for path resolution and manipulation.
 
+use crate::cache::FileStamp;
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
-/// Cache for file existence checks
-static FILE_EXISTS_CACHE: Lazy<Mutex<HashMap<String, bool>>> =
+/// Names in a directory, stamped with the metadata of the directory when it was listed
+struct DirEntries {
+    stamp: FileStamp,
+    names: HashSet<String>,
+}
+
+/// Cache for file existence checks, listing each directory once
+static DIR_ENTRIES_CACHE: Lazy<Mutex<HashMap<String, DirEntries>>> =
     Lazy::new(|| Mutex::new(HashMap::new()));
 
+/// Lists the names in a directory, leaving out dangling symlinks
+fn list_dir(dir: &str) -> Option<HashSet<String>> {
+    let names = fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .filter(|entry| match entry.file_type() {
+            Ok(file_type) if file_type.is_symlink() => entry.path().exists(),
+            Ok(_) => true,
+            Err(_) => false,
+        })
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+
+    Some(names)
+}
+
 /// Fast file existence check with caching
 ///
+/// The entries of the directory containing the file are listed once and reused while the
+/// stamp of the directory is unchanged. Creating, removing or renaming a file changes the
+/// stamp, so both existing and missing files are cached without missing files created
+/// while the process is running, e.g. by a dev server.
+///
 /// # Arguments
 ///
 /// * `path` - The file path to check
@@ -21,23 +50,91 @@ static FILE_EXISTS_CACHE: Lazy<Mutex<HashMap<String, bool>>> =
 ///
 /// `true` if the file exists, `false` otherwise
 pub fn file_exists(path: &str) -> bool {
-    // Check cache first
-    if let Ok(cache) = FILE_EXISTS_CACHE.lock() {
-        if let Some(&exists) = cache.get(path) {
-            return exists;
+    let Some((dir, name)) = path.rsplit_once('/') else {
+        return Path::new(path).exists();
+    };
+    let dir = if dir.is_empty() { "/" } else { dir };
+
+    // Directories without a stamp, e.g. missing ones, are not cached
+    let Some(stamp) = FileStamp::of(dir) else {
+        return Path::new(path).exists();
+    };
+
+    let Ok(mut cache) = DIR_ENTRIES_CACHE.lock() else {
+        return Path::new(path).exists();
+    };
+
+    if let Some(entries) = cache.get(dir) {
+        if entries.stamp == stamp {
+            return entries.names.contains(name);
         }
     }
 
-    let exists = Path::new(path).exists();
+    let Some(names) = list_dir(dir) else {
+        return Path::new(path).exists();
+    };
+    let exists = names.contains(name);
 
-    // Cache the result
-    if let Ok(mut cache) = FILE_EXISTS_CACHE.lock() {
-        cache.insert(path.to_string(), exists);
-    }
+    cache.insert(dir.to_string(), DirEntries { stamp, names });
 
     exists
 }
 
+/// Extensions probed when resolving module paths without an extension
+const MODULE_EXTENSIONS: [&str; 4] = ["ts", "tsx", "js", "jsx"];
+
+/// Resolves a module path to an existing file
+///
+/// Tries the path as is, then with known extensions appended, then as a directory
+/// with an index file.
+///
+/// # Arguments
+///
+/// * `path` - The module path to resolve
+///
+/// # Returns
+///
+/// The path of the existing file if found, `None` otherwise
+pub fn resolve_module_file(path: &str) -> Option<String> {
+    if Path::new(path).is_file() {
+        return Some(path.to_string());
+    }
+
+    MODULE_EXTENSIONS
+        .iter()
+        .map(|extension| format!("{}.{}", path, extension))
+        .chain(
+            MODULE_EXTENSIONS
+                .iter()
+                .map(|extension| format!("{}/index.{}", path, extension)),
+        )
+        .find(|candidate| file_exists(candidate))
+}
+
+/// Extensions of emitted scripts, which TypeScript resolves to their sources
+const EMITTED_EXTENSIONS: [&str; 4] = [".js", ".jsx", ".mjs", ".cjs"];
+
+/// Resolves the path of an imported module to its source file
+///
+/// Like [`resolve_module_file`], but paths with the extension of an emitted script are also
+/// resolved to their TypeScript sources, e.g. `./Button.js` to `./Button.tsx`.
+///
+/// # Arguments
+///
+/// * `path` - The module path to resolve
+///
+/// # Returns
+///
+/// The path of the existing file if found, `None` otherwise
+pub fn resolve_source_file(path: &str) -> Option<String> {
+    resolve_module_file(path).or_else(|| {
+        EMITTED_EXTENSIONS
+            .iter()
+            .filter_map(|extension| path.strip_suffix(extension))
+            .find_map(resolve_module_file)
+    })
+}
+
 /// Calculates a relative path from one absolute path to another
 ///
 /// # Arguments
@@ -181,6 +278,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_file_exists() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-exists-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let file = |name: &str| dir.join(name).to_string_lossy().to_string();
+
+        fs::write(file("index.ts"), "").unwrap();
+        assert!(file_exists(&file("index.ts")));
+        assert!(!file_exists(&file("index.tsx")));
+        assert!(!file_exists(&file("missing/index.ts")));
+
+        // Files created or removed after a check are found
+        fs::write(file("index.tsx"), "").unwrap();
+        assert!(file_exists(&file("index.tsx")));
+        fs::remove_file(file("index.ts")).unwrap();
+        assert!(!file_exists(&file("index.ts")));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_module_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-resolve-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(dir.join("ui")).unwrap();
+        std::fs::write(dir.join("Button.tsx"), "").unwrap();
+        std::fs::write(dir.join("ui/index.ts"), "").unwrap();
+        let dir = dir.to_string_lossy().to_string();
+
+        // Exact file
+        assert_eq!(
+            resolve_module_file(&format!("{}/Button.tsx", dir)),
+            Some(format!("{}/Button.tsx", dir))
+        );
+        // Extension probing
+        assert_eq!(
+            resolve_module_file(&format!("{}/Button", dir)),
+            Some(format!("{}/Button.tsx", dir))
+        );
+        // Directory index
+        assert_eq!(
+            resolve_module_file(&format!("{}/ui", dir)),
+            Some(format!("{}/ui/index.ts", dir))
+        );
+        // Missing module
+        assert_eq!(resolve_module_file(&format!("{}/Input", dir)), None);
+
+        // Emitted script extensions resolve to the sources
+        assert_eq!(resolve_module_file(&format!("{}/Button.js", dir)), None);
+        assert_eq!(
+            resolve_source_file(&format!("{}/Button.js", dir)),
+            Some(format!("{}/Button.tsx", dir))
+        );
+        assert_eq!(resolve_source_file(&format!("{}/Input.js", dir)), None);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_dirname() {
         // Test with normal path
@@ -26,6 +26,11 @@ impl CompiledPattern {
         })
     }
 
+    /// Returns the literal part of the pattern before the first wildcard
+    pub fn literal_prefix(&self) -> &str {
+        self.parts.first().map(String::as_str).unwrap_or_default()
+    }
+
     /// Checks if a path matches this pattern
     pub fn matches(&self, path: &str) -> bool {
         if self.parts.is_empty() {
@@ -98,6 +103,26 @@ impl CompiledPattern {
         true
     }
 
+    /// Finds the longest prefix of a path matched by this pattern
+    ///
+    /// Only prefixes ending right before a `/` separator are considered, so the rest of the
+    /// path always starts with `/`.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to match
+    ///
+    /// # Returns
+    ///
+    /// The length of the matched prefix if found, `None` otherwise
+    pub fn match_prefix(&self, path: &str) -> Option<usize> {
+        path.match_indices('/')
+            .map(|(index, _)| index)
+            .filter(|&index| index > 0)
+            .rev()
+            .find(|&index| self.matches(&path[..index]))
+    }
+
     /// Extracts components from a path using this pattern
     pub fn extract_components(&self, path: &str) -> HashMap<String, String> {
         let mut components = HashMap::new();
@@ -161,6 +186,147 @@ impl CompiledPattern {
     }
 }
 
+/// Node of the literal prefix trie used by [`PatternIndex`]
+#[derive(Clone, Default)]
+struct PrefixTrieNode {
+    /// Child nodes keyed by the next byte of the literal prefix, sorted by byte
+    children: Vec<(u8, PrefixTrieNode)>,
+    /// Indices of patterns whose literal prefix ends at this node
+    entries: Vec<usize>,
+}
+
+impl PrefixTrieNode {
+    /// Returns the child node for the next byte
+    fn child(&self, byte: u8) -> Option<&PrefixTrieNode> {
+        self.children
+            .binary_search_by_key(&byte, |(key, _)| *key)
+            .ok()
+            .map(|index| &self.children[index].1)
+    }
+
+    /// Returns the child node for the next byte, inserting it if missing
+    fn child_mut(&mut self, byte: u8) -> &mut PrefixTrieNode {
+        let index = match self.children.binary_search_by_key(&byte, |(key, _)| *key) {
+            Ok(index) => index,
+            Err(index) => {
+                self.children
+                    .insert(index, (byte, PrefixTrieNode::default()));
+                index
+            }
+        };
+
+        &mut self.children[index].1
+    }
+}
+
+/// Number of patterns from which [`PatternIndex`] looks them up through the prefix trie
+///
+/// Smaller sets are matched faster by checking every pattern, see `perf/README.md`.
+const MIN_INDEXED_PATTERNS: usize = 32;
+
+/// Index of compiled patterns keyed by their literal prefix
+///
+/// Patterns are stored in a byte trie over `CompiledPattern.parts[0]`, so only the patterns
+/// whose literal prefix is a prefix of the looked up path are checked with
+/// [`CompiledPattern::matches`]. Lookup cost depends on the path length and on the number of
+/// candidates sharing its prefix instead of on the total number of patterns. Sets of fewer
+/// than `MIN_INDEXED_PATTERNS` patterns are scanned linearly instead.
+#[derive(Clone)]
+pub struct PatternIndex {
+    /// Indexed patterns in their original order
+    patterns: Vec<CompiledPattern>,
+    /// Trie over the literal prefixes of the patterns, `None` for small sets of patterns
+    root: Option<PrefixTrieNode>,
+}
+
+impl PatternIndex {
+    /// Creates a new index, preserving the order of the given patterns
+    pub fn new(patterns: Vec<CompiledPattern>) -> Self {
+        let use_trie = patterns.len() >= MIN_INDEXED_PATTERNS;
+        Self::build(patterns, use_trie)
+    }
+
+    /// Creates a new index, with or without the prefix trie
+    fn build(patterns: Vec<CompiledPattern>, use_trie: bool) -> Self {
+        let root = use_trie.then(|| {
+            let mut root = PrefixTrieNode::default();
+
+            for (index, pattern) in patterns.iter().enumerate() {
+                let mut node = &mut root;
+                for byte in pattern.literal_prefix().bytes() {
+                    node = node.child_mut(byte);
+                }
+                node.entries.push(index);
+            }
+
+            root
+        });
+
+        Self { patterns, root }
+    }
+
+    /// Visits the indices of the patterns whose literal prefix is a prefix of the path
+    ///
+    /// Candidates are visited in trie order, shorter prefixes first, without allocating.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The root of the prefix trie
+    /// * `path` - The path to look up
+    ///
+    /// # Returns
+    ///
+    /// An iterator over the candidate pattern indices
+    fn candidates<'a>(root: &'a PrefixTrieNode, path: &'a str) -> impl Iterator<Item = usize> + 'a {
+        let mut bytes = path.bytes();
+        let mut next_node = Some(root);
+
+        std::iter::from_fn(move || {
+            let node = next_node?;
+            next_node = bytes.next().and_then(|byte| node.child(byte));
+            Some(node)
+        })
+        .flat_map(|node| node.entries.iter().copied())
+    }
+
+    /// Finds the first pattern (in original order) matching the path
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to match
+    ///
+    /// # Returns
+    ///
+    /// The index of the matching pattern if found, `None` otherwise
+    pub fn find(&self, path: &str) -> Option<usize> {
+        match &self.root {
+            Some(root) => Self::candidates(root, path)
+                .filter(|&index| self.patterns[index].matches(path))
+                .min(),
+            None => self
+                .patterns
+                .iter()
+                .position(|pattern| pattern.matches(path)),
+        }
+    }
+
+    /// Finds all patterns matching the path, in original order
+    pub fn find_all(&self, path: &str) -> Vec<usize> {
+        let Some(root) = &self.root else {
+            return (0..self.patterns.len())
+                .filter(|&index| self.patterns[index].matches(path))
+                .collect();
+        };
+
+        let mut matches: Vec<usize> = Self::candidates(root, path)
+            .filter(|&index| self.patterns[index].matches(path))
+            .collect();
+
+        matches.sort_unstable();
+        matches
+    }
+}
+
 /// Applies extracted components to a path template
 ///
 /// # Arguments
@@ -287,6 +453,29 @@ mod tests {
         assert_eq!(components.get("p0"), Some(&"Button".to_string()));
     }
 
+    #[test]
+    fn test_match_prefix() {
+        let pattern = CompiledPattern::new("/cwd/src/modules/*").unwrap();
+        assert_eq!(
+            pattern.match_prefix("/cwd/src/modules/user/models/User"),
+            Some("/cwd/src/modules/user".len())
+        );
+        assert_eq!(pattern.match_prefix("/cwd/src/modules/user"), None);
+        assert_eq!(pattern.match_prefix("/cwd/src/shared/user/models"), None);
+
+        let pattern = CompiledPattern::new("/cwd/src/*/ui").unwrap();
+        assert_eq!(
+            pattern.match_prefix("/cwd/src/user/ui/Button"),
+            Some("/cwd/src/user/ui".len())
+        );
+
+        let pattern = CompiledPattern::new("/cwd/src").unwrap();
+        assert_eq!(
+            pattern.match_prefix("/cwd/src/modules/user"),
+            Some("/cwd/src".len())
+        );
+    }
+
     #[test]
     fn test_apply_components_to_template() {
         // Single wildcard
@@ -313,4 +502,93 @@ mod tests {
         let result = apply_components_to_template("*/*/template", &components);
         assert_eq!(result, "first/second/template");
     }
+
+    #[test]
+    fn test_pattern_index() {
+        let patterns = vec![
+            CompiledPattern::new("/cwd/src/features/*/index.ts").unwrap(),
+            CompiledPattern::new("/cwd/src/entities/*/index.ts").unwrap(),
+            CompiledPattern::new("/cwd/src/features/auth/index.ts").unwrap(),
+            CompiledPattern::new("*/index.ts").unwrap(),
+        ];
+
+        // Small sets of patterns are scanned linearly, with the same results
+        for use_trie in [true, false] {
+            let index = PatternIndex::build(patterns.clone(), use_trie);
+
+            // Patterns are checked in their original order
+            assert_eq!(index.find("/cwd/src/features/auth/index.ts"), Some(0));
+            assert_eq!(index.find("/cwd/src/entities/user/index.ts"), Some(1));
+            assert_eq!(
+                index.find_all("/cwd/src/features/auth/index.ts"),
+                vec![0, 2]
+            );
+
+            // Patterns with an empty literal prefix are always candidates
+            assert_eq!(index.find("ui/index.ts"), Some(3));
+
+            assert_eq!(index.find("/cwd/src/shared/ui/index.ts"), None);
+            assert_eq!(index.find("/cwd/src/features/auth/model.ts"), None);
+        }
+
+        assert!(PatternIndex::new(patterns).root.is_none());
+    }
+
+    #[test]
+    fn test_pattern_index_matches_linear_scan() {
+        let sources = [
+            "#features/*",
+            "#features/*/testing",
+            "#features/auth",
+            "#entities/*",
+            "@direct-frontend/stdlib",
+            "@direct-frontend/components/*",
+            "*",
+        ];
+        let patterns: Vec<CompiledPattern> = sources
+            .iter()
+            .map(|pattern| CompiledPattern::new(pattern).unwrap())
+            .collect();
+        let index = PatternIndex::build(patterns.clone(), true);
+
+        let paths = [
+            "#features/auth",
+            "#features/auth/testing",
+            "#features/auth/model",
+            "#entities/user",
+            "@direct-frontend/stdlib",
+            "@direct-frontend/components/Button",
+            "#feat",
+            "react",
+            "",
+        ];
+
+        for path in paths {
+            let expected = patterns.iter().position(|pattern| pattern.matches(path));
+            assert_eq!(index.find(path), expected, "path: {}", path);
+        }
+    }
+
+    #[test]
+    fn test_empty_pattern_index() {
+        for use_trie in [true, false] {
+            let index = PatternIndex::build(vec![], use_trie);
+
+            assert_eq!(index.find("#features/auth"), None);
+            assert_eq!(index.find(""), None);
+        }
+    }
+
+    #[test]
+    fn test_large_pattern_index() {
+        let patterns: Vec<CompiledPattern> = (0..MIN_INDEXED_PATTERNS)
+            .map(|index| CompiledPattern::new(&format!("/cwd/src/module{}/*.ts", index)).unwrap())
+            .collect();
+        let index = PatternIndex::new(patterns);
+
+        assert!(index.root.is_some());
+        assert_eq!(index.find("/cwd/src/module12/index.ts"), Some(12));
+        assert_eq!(index.find_all("/cwd/src/module1/index.ts"), vec![1]);
+        assert_eq!(index.find("/cwd/src/other/index.ts"), None);
+    }
 }
@@ -2,13 +2,17 @@
 //!
 //! This module provides functionality for analyzing barrel files and extracting re-export information.
 
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use swc_core::common::{Span, Spanned};
 use swc_core::ecma::ast::{
-    Decl, ExportSpecifier, Module, ModuleDecl, ModuleExportName, ModuleItem,
+    Decl, ExportSpecifier, Module, ModuleDecl, ModuleExportName, ModuleItem, ObjectPatProp, Pat,
+    TsModuleName,
 };
 
 /// Represents a re-export from a barrel file
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ReExport {
     /// The name under which the export is exposed (may be renamed)
     pub exported_name: String,
@@ -21,6 +25,9 @@ pub struct ReExport {
 
     /// Whether this is a default export
     pub is_default: bool,
+
+    /// Whether this is a type-only export (`export type { ... }` or `export { type ... }`)
+    pub is_type_only: bool,
 }
 
 /// Error type for barrel file analysis
@@ -37,6 +44,26 @@ pub enum BarrelError {
 
     /// The barrel file contains an export declaration without a source
     MissingSource(String),
+
+    /// The barrel file exports the same name more than once
+    DuplicateExport(String),
+
+    /// The barrel file re-exports a module that does not exist
+    UnresolvedSource(String),
+}
+
+impl BarrelError {
+    /// Returns the kind of the error, e.g. for lint reports
+    pub fn kind(&self) -> &'static str {
+        match self {
+            BarrelError::NonExportCode(_) => "non-export-code",
+            BarrelError::WildcardExport(_) => "wildcard-export",
+            BarrelError::NamespaceExport(_) => "namespace-export",
+            BarrelError::MissingSource(_) => "missing-source",
+            BarrelError::DuplicateExport(_) => "duplicate-export",
+            BarrelError::UnresolvedSource(_) => "unresolved-source",
+        }
+    }
 }
 
 impl std::fmt::Display for BarrelError {
@@ -58,12 +85,28 @@ impl std::fmt::Display for BarrelError {
             BarrelError::MissingSource(msg) => {
                 write!(f, "Export declaration without source: {}", msg)
             }
+            BarrelError::DuplicateExport(msg) => {
+                write!(f, "Duplicate export in barrel file: {}", msg)
+            }
+            BarrelError::UnresolvedSource(msg) => {
+                write!(f, "Re-exported module not found: {}", msg)
+            }
         }
     }
 }
 
 impl std::error::Error for BarrelError {}
 
+/// Violation of the barrel file rules, located in the barrel file
+#[derive(Debug, Clone, PartialEq)]
+pub struct BarrelViolation {
+    /// The violated rule
+    pub error: BarrelError,
+
+    /// The span of the offending item in the barrel file
+    pub span: Span,
+}
+
 /// Validates that a file only contains re-exports
 ///
 /// # Arguments
@@ -72,99 +115,109 @@ impl std::error::Error for BarrelError {}
 ///
 /// # Returns
 ///
-/// `Ok(())` if the file only contains re-exports, `Err` otherwise
-fn validate_barrel_file(ast: &Module) -> Result<(), BarrelError> {
+/// `Ok(())` if the file only contains re-exports, `Err` with the first offending item otherwise
+fn validate_barrel_file(ast: &Module) -> Result<(), BarrelViolation> {
     // Check that the file only contains export declarations
     for item in &ast.body {
-        match item {
-            ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(_)) => {
-                // Named exports are allowed
-            }
-            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
-                // Check that the export declaration only contains simple declarations
-                match &export_decl.decl {
-                    Decl::Var(_) => {
-                        return Err(BarrelError::NonExportCode(
-                            "Variable declarations are not allowed in barrel files".into(),
-                        ));
-                    }
-                    Decl::Class(_) => {
-                        return Err(BarrelError::NonExportCode(
-                            "Class declarations are not allowed in barrel files".into(),
-                        ));
-                    }
-                    Decl::Fn(_) => {
-                        return Err(BarrelError::NonExportCode(
-                            "Function declarations are not allowed in barrel files".into(),
-                        ));
-                    }
-                    Decl::TsInterface(_) => {
-                        return Err(BarrelError::NonExportCode(
-                            "Interface declarations are not allowed in barrel files".into(),
-                        ));
-                    }
-                    Decl::TsTypeAlias(_) => {
-                        return Err(BarrelError::NonExportCode(
-                            "Type alias declarations are not allowed in barrel files".into(),
-                        ));
-                    }
-                    Decl::TsEnum(_) => {
-                        return Err(BarrelError::NonExportCode(
-                            "Enum declarations are not allowed in barrel files".into(),
-                        ));
-                    }
-                    Decl::TsModule(_) => {
-                        return Err(BarrelError::NonExportCode(
-                            "Module declarations are not allowed in barrel files".into(),
-                        ));
-                    }
-                    _ => {
-                        return Err(BarrelError::NonExportCode(
-                            "Unknown declaration type in barrel file".into(),
-                        ));
-                    }
+        validate_item(item).map_err(|error| BarrelViolation {
+            error,
+            span: item.span(),
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Validates that a barrel file item is a re-export
+fn validate_item(item: &ModuleItem) -> Result<(), BarrelError> {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(_)) => {
+            // Named exports are allowed
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export_decl)) => {
+            // Check that the export declaration only contains simple declarations
+            match &export_decl.decl {
+                Decl::Var(_) => {
+                    return Err(BarrelError::NonExportCode(
+                        "Variable declarations are not allowed in barrel files".into(),
+                    ));
+                }
+                Decl::Class(_) => {
+                    return Err(BarrelError::NonExportCode(
+                        "Class declarations are not allowed in barrel files".into(),
+                    ));
+                }
+                Decl::Fn(_) => {
+                    return Err(BarrelError::NonExportCode(
+                        "Function declarations are not allowed in barrel files".into(),
+                    ));
+                }
+                Decl::TsInterface(_) => {
+                    return Err(BarrelError::NonExportCode(
+                        "Interface declarations are not allowed in barrel files".into(),
+                    ));
+                }
+                Decl::TsTypeAlias(_) => {
+                    return Err(BarrelError::NonExportCode(
+                        "Type alias declarations are not allowed in barrel files".into(),
+                    ));
+                }
+                Decl::TsEnum(_) => {
+                    return Err(BarrelError::NonExportCode(
+                        "Enum declarations are not allowed in barrel files".into(),
+                    ));
+                }
+                Decl::TsModule(_) => {
+                    return Err(BarrelError::NonExportCode(
+                        "Module declarations are not allowed in barrel files".into(),
+                    ));
+                }
+                _ => {
+                    return Err(BarrelError::NonExportCode(
+                        "Unknown declaration type in barrel file".into(),
+                    ));
                 }
             }
-            ModuleItem::ModuleDecl(ModuleDecl::ExportAll(_)) => {
-                return Err(BarrelError::WildcardExport(
-                    "Wildcard exports are not allowed in barrel files".into(),
-                ));
-            }
-            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(_)) => {
-                return Err(BarrelError::NonExportCode(
-                    "Default export declarations are not allowed in barrel files".into(),
-                ));
-            }
-            ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(_)) => {
-                return Err(BarrelError::NonExportCode(
-                    "Default export expressions are not allowed in barrel files".into(),
-                ));
-            }
-            ModuleItem::ModuleDecl(ModuleDecl::Import(_)) => {
-                return Err(BarrelError::NonExportCode(
-                    "Import declarations are not allowed in barrel files".into(),
-                ));
-            }
-            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(_)) => {
-                return Err(BarrelError::NonExportCode(
-                    "TypeScript import equals declarations are not allowed in barrel files".into(),
-                ));
-            }
-            ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(_)) => {
-                return Err(BarrelError::NonExportCode(
-                    "TypeScript export assignments are not allowed in barrel files".into(),
-                ));
-            }
-            ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(_)) => {
-                return Err(BarrelError::NonExportCode(
-                    "TypeScript namespace exports are not allowed in barrel files".into(),
-                ));
-            }
-            ModuleItem::Stmt(_) => {
-                return Err(BarrelError::NonExportCode(
-                    "Statements are not allowed in barrel files".into(),
-                ));
-            }
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportAll(_)) => {
+            return Err(BarrelError::WildcardExport(
+                "Wildcard exports are not allowed in barrel files".into(),
+            ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(_)) => {
+            return Err(BarrelError::NonExportCode(
+                "Default export declarations are not allowed in barrel files".into(),
+            ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(_)) => {
+            return Err(BarrelError::NonExportCode(
+                "Default export expressions are not allowed in barrel files".into(),
+            ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::Import(_)) => {
+            return Err(BarrelError::NonExportCode(
+                "Import declarations are not allowed in barrel files".into(),
+            ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(_)) => {
+            return Err(BarrelError::NonExportCode(
+                "TypeScript import equals declarations are not allowed in barrel files".into(),
+            ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(_)) => {
+            return Err(BarrelError::NonExportCode(
+                "TypeScript export assignments are not allowed in barrel files".into(),
+            ));
+        }
+        ModuleItem::ModuleDecl(ModuleDecl::TsNamespaceExport(_)) => {
+            return Err(BarrelError::NonExportCode(
+                "TypeScript namespace exports are not allowed in barrel files".into(),
+            ));
+        }
+        ModuleItem::Stmt(_) => {
+            return Err(BarrelError::NonExportCode(
+                "Statements are not allowed in barrel files".into(),
+            ));
         }
     }
 
@@ -180,8 +233,12 @@ fn validate_barrel_file(ast: &Module) -> Result<(), BarrelError> {
 ///
 /// # Returns
 ///
-/// A list of re-exports if the file is a valid barrel file, `Err` otherwise
-pub fn analyze_barrel_file(ast: &Module, file_path: &str) -> Result<Vec<ReExport>, BarrelError> {
+/// A list of re-exports if the file is a valid barrel file, `Err` with the first offending
+/// item or the first name exported more than once otherwise
+pub fn analyze_barrel_file(
+    ast: &Module,
+    file_path: &str,
+) -> Result<Vec<ReExport>, BarrelViolation> {
     validate_barrel_file(ast)?;
 
     let mut re_exports = Vec::new();
@@ -190,97 +247,305 @@ pub fn analyze_barrel_file(ast: &Module, file_path: &str) -> Result<Vec<ReExport
         .unwrap_or_else(|| Path::new(""));
 
     for item in &ast.body {
-        if let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) = item {
-            // Handle named exports
-            for specifier in &export.specifiers {
-                match specifier {
-                    ExportSpecifier::Named(named) => {
-                        let exported_name = match &named.exported {
-                            Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
-                            Some(ModuleExportName::Str(str)) => str.value.to_string(),
-                            None => match &named.orig {
-                                ModuleExportName::Ident(ident) => ident.sym.to_string(),
-                                ModuleExportName::Str(str) => str.value.to_string(),
-                            },
-                        };
+        collect_re_exports(item, &mut re_exports).map_err(|error| BarrelViolation {
+            error,
+            span: item.span(),
+        })?;
+    }
+
+    // Imports of a name exported twice would resolve to whichever re-export is found first
+    if let Some(violation) = find_duplicate_exports(&re_exports).into_iter().next() {
+        return Err(violation);
+    }
 
-                        let original_name = match &named.orig {
+    Ok(re_exports
+        .into_iter()
+        .map(|(re_export, _)| re_export)
+        .collect())
+}
+
+/// Finds all violations of the barrel file rules
+///
+/// Unlike [`analyze_barrel_file`], all offending items are reported instead of the first
+/// one, as well as names exported more than once.
+///
+/// # Arguments
+///
+/// * `ast` - The AST of the barrel file
+///
+/// # Returns
+///
+/// The re-exports of the valid items with the spans of their specifiers, and the violations
+/// in the order of the items
+pub fn find_violations(ast: &Module) -> (Vec<(ReExport, Span)>, Vec<BarrelViolation>) {
+    let mut re_exports = Vec::new();
+    let mut violations = Vec::new();
+
+    for item in &ast.body {
+        let mut item_re_exports = Vec::new();
+
+        match validate_item(item).and_then(|_| collect_re_exports(item, &mut item_re_exports)) {
+            Ok(()) => re_exports.extend(item_re_exports),
+            Err(error) => violations.push(BarrelViolation {
+                error,
+                span: item.span(),
+            }),
+        }
+    }
+
+    violations.extend(find_duplicate_exports(&re_exports));
+
+    (re_exports, violations)
+}
+
+/// Finds the re-exports of names already exported by a previous re-export
+///
+/// ESM modules cannot export the same name twice, even if both exports point to the same
+/// binding.
+fn find_duplicate_exports(re_exports: &[(ReExport, Span)]) -> Vec<BarrelViolation> {
+    let mut first_exports: HashMap<&str, &ReExport> = HashMap::new();
+    let mut violations = Vec::new();
+
+    for (re_export, span) in re_exports {
+        match first_exports.get(re_export.exported_name.as_str()) {
+            Some(first_export) => violations.push(BarrelViolation {
+                error: BarrelError::DuplicateExport(format!(
+                    "'{}' is exported from '{}' and '{}'",
+                    re_export.exported_name, first_export.source_path, re_export.source_path
+                )),
+                span: *span,
+            }),
+            None => {
+                first_exports.insert(&re_export.exported_name, re_export);
+            }
+        }
+    }
+
+    violations
+}
+
+/// Collects the re-exports declared by a barrel file item with the spans of their specifiers
+fn collect_re_exports(
+    item: &ModuleItem,
+    re_exports: &mut Vec<(ReExport, Span)>,
+) -> Result<(), BarrelError> {
+    if let ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) = item {
+        // Handle named exports
+        for specifier in &export.specifiers {
+            match specifier {
+                ExportSpecifier::Named(named) => {
+                    let exported_name = match &named.exported {
+                        Some(ModuleExportName::Ident(ident)) => ident.sym.to_string(),
+                        Some(ModuleExportName::Str(str)) => str.value.to_string(),
+                        None => match &named.orig {
                             ModuleExportName::Ident(ident) => ident.sym.to_string(),
                             ModuleExportName::Str(str) => str.value.to_string(),
-                        };
+                        },
+                    };
+
+                    let original_name = match &named.orig {
+                        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+                        ModuleExportName::Str(str) => str.value.to_string(),
+                    };
 
-                        if let Some(src) = &export.src {
-                            let source_path = src.value.to_string();
+                    if let Some(src) = &export.src {
+                        let source_path = src.value.to_string();
 
-                            re_exports.push(ReExport {
+                        re_exports.push((
+                            ReExport {
                                 exported_name,
                                 source_path,
                                 original_name: original_name.clone(),
                                 is_default: original_name == "default",
-                            });
-                        } else {
-                            return Err(BarrelError::MissingSource(format!(
-                                "Export '{}' does not have a source",
-                                exported_name
-                            )));
-                        }
+                                is_type_only: export.type_only || named.is_type_only,
+                            },
+                            named.span,
+                        ));
+                    } else {
+                        return Err(BarrelError::MissingSource(format!(
+                            "Export '{}' does not have a source",
+                            exported_name
+                        )));
                     }
-                    ExportSpecifier::Default(default) => {
-                        if let Some(src) = &export.src {
-                            let source_path = src.value.to_string();
+                }
+                ExportSpecifier::Default(default) => {
+                    if let Some(src) = &export.src {
+                        let source_path = src.value.to_string();
 
-                            re_exports.push(ReExport {
+                        re_exports.push((
+                            ReExport {
                                 exported_name: default.exported.sym.to_string(),
                                 source_path,
                                 original_name: "default".to_string(),
                                 is_default: true,
-                            });
-                        } else {
-                            return Err(BarrelError::MissingSource(
-                                "Default export does not have a source".to_string(),
-                            ));
-                        }
+                                is_type_only: export.type_only,
+                            },
+                            default.exported.span,
+                        ));
+                    } else {
+                        return Err(BarrelError::MissingSource(
+                            "Default export does not have a source".to_string(),
+                        ));
                     }
-                    ExportSpecifier::Namespace(ns) => {
-                        let exported_name = match &ns.name {
-                            ModuleExportName::Ident(ident) => ident.sym.to_string(),
-                            ModuleExportName::Str(str) => str.value.to_string(),
-                        };
-
-                        if let Some(src) = &export.src {
-                            let source_path = src.value.to_string();
-
-                            return Err(BarrelError::NamespaceExport(format!(
-                                "export * as {} from '{}'",
-                                exported_name, source_path
-                            )));
-                        } else {
-                            return Err(BarrelError::MissingSource(format!(
-                                "Namespace export '{}' does not have a source",
-                                exported_name
-                            )));
+                }
+                ExportSpecifier::Namespace(ns) => {
+                    let exported_name = match &ns.name {
+                        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+                        ModuleExportName::Str(str) => str.value.to_string(),
+                    };
+
+                    if let Some(src) = &export.src {
+                        let source_path = src.value.to_string();
+
+                        return Err(BarrelError::NamespaceExport(format!(
+                            "export * as {} from '{}'",
+                            exported_name, source_path
+                        )));
+                    } else {
+                        return Err(BarrelError::MissingSource(format!(
+                            "Namespace export '{}' does not have a source",
+                            exported_name
+                        )));
+                    }
+                }
+            }
+        }
+    } else if let ModuleItem::ModuleDecl(ModuleDecl::ExportAll(_)) = item {
+        return Err(BarrelError::WildcardExport(
+            "Wildcard exports are not allowed in barrel files".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Lists the names exported by a module
+///
+/// # Arguments
+///
+/// * `ast` - The AST of the module
+///
+/// # Returns
+///
+/// The exported names, including `default` and type exports. `None` if they cannot be
+/// determined statically: for modules with wildcard re-exports, `export =` assignments or
+/// without any ESM export, like CommonJS modules.
+pub fn exported_names(ast: &Module) -> Option<HashSet<String>> {
+    let mut names = HashSet::new();
+
+    for item in &ast.body {
+        let ModuleItem::ModuleDecl(decl) = item else {
+            continue;
+        };
+
+        match decl {
+            ModuleDecl::ExportDecl(export) => match &export.decl {
+                Decl::Class(class) => {
+                    names.insert(class.ident.sym.to_string());
+                }
+                Decl::Fn(function) => {
+                    names.insert(function.ident.sym.to_string());
+                }
+                Decl::Var(var) => {
+                    for declarator in &var.decls {
+                        collect_binding_names(&declarator.name, &mut names);
+                    }
+                }
+                Decl::Using(using) => {
+                    for declarator in &using.decls {
+                        collect_binding_names(&declarator.name, &mut names);
+                    }
+                }
+                Decl::TsInterface(interface) => {
+                    names.insert(interface.id.sym.to_string());
+                }
+                Decl::TsTypeAlias(alias) => {
+                    names.insert(alias.id.sym.to_string());
+                }
+                Decl::TsEnum(ts_enum) => {
+                    names.insert(ts_enum.id.sym.to_string());
+                }
+                Decl::TsModule(module) => {
+                    if let TsModuleName::Ident(ident) = &module.id {
+                        names.insert(ident.sym.to_string());
+                    }
+                }
+            },
+            ModuleDecl::ExportNamed(export) => {
+                for specifier in &export.specifiers {
+                    let name = match specifier {
+                        ExportSpecifier::Named(named) => {
+                            module_export_name(named.exported.as_ref().unwrap_or(&named.orig))
                         }
+                        ExportSpecifier::Default(default) => default.exported.sym.to_string(),
+                        ExportSpecifier::Namespace(namespace) => {
+                            module_export_name(&namespace.name)
+                        }
+                    };
+                    names.insert(name);
+                }
+            }
+            ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
+                names.insert("default".to_string());
+            }
+            ModuleDecl::ExportAll(_) | ModuleDecl::TsExportAssignment(_) => return None,
+            ModuleDecl::Import(_)
+            | ModuleDecl::TsImportEquals(_)
+            | ModuleDecl::TsNamespaceExport(_) => {}
+        }
+    }
+
+    (!names.is_empty()).then_some(names)
+}
+
+/// Collects the names bound by a variable declaration pattern
+fn collect_binding_names(pat: &Pat, names: &mut HashSet<String>) {
+    match pat {
+        Pat::Ident(ident) => {
+            names.insert(ident.id.sym.to_string());
+        }
+        Pat::Array(array) => {
+            for element in array.elems.iter().flatten() {
+                collect_binding_names(element, names);
+            }
+        }
+        Pat::Object(object) => {
+            for prop in &object.props {
+                match prop {
+                    ObjectPatProp::KeyValue(key_value) => {
+                        collect_binding_names(&key_value.value, names)
                     }
+                    ObjectPatProp::Assign(assign) => {
+                        names.insert(assign.key.sym.to_string());
+                    }
+                    ObjectPatProp::Rest(rest) => collect_binding_names(&rest.arg, names),
                 }
             }
-        } else if let ModuleItem::ModuleDecl(ModuleDecl::ExportAll(_)) = item {
-            return Err(BarrelError::WildcardExport(
-                "Wildcard exports are not allowed in barrel files".to_string(),
-            ));
         }
+        Pat::Rest(rest) => collect_binding_names(&rest.arg, names),
+        Pat::Assign(assign) => collect_binding_names(&assign.left, names),
+        Pat::Invalid(_) | Pat::Expr(_) => {}
     }
+}
 
-    Ok(re_exports)
+/// Converts an export name to a string
+fn module_export_name(name: &ModuleExportName) -> String {
+    match name {
+        ModuleExportName::Ident(ident) => ident.sym.to_string(),
+        ModuleExportName::Str(str) => str.value.to_string(),
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use swc_core::common::DUMMY_SP;
+    use swc_core::common::sync::Lrc;
+    use swc_core::common::{BytePos, FileName, SourceMap, DUMMY_SP};
     use swc_core::ecma::ast::{
         BlockStmt, DefaultDecl, EmptyStmt, ExportAll, ExportNamedSpecifier, FnExpr, Ident,
         ImportDecl, ImportNamedSpecifier, ImportSpecifier, NamedExport, Stmt, Str,
     };
+    use swc_core::ecma::parser::{parse_file_as_module, Syntax};
 
     #[test]
     fn test_validate_barrel_file() {
@@ -317,7 +582,7 @@ mod tests {
         module.body.push(ModuleItem::ModuleDecl(named_export));
 
         // Validate the barrel file
-        let result = validate_barrel_file(&module);
+        let result = validate_barrel_file(&module).map_err(|violation| violation.error);
         assert!(result.is_ok());
 
         // Create an invalid barrel file AST with a import declaration
@@ -354,7 +619,7 @@ mod tests {
         module.body.push(ModuleItem::ModuleDecl(import_decl));
 
         // Validate the barrel file
-        let result = validate_barrel_file(&module);
+        let result = validate_barrel_file(&module).map_err(|violation| violation.error);
         assert!(result.is_err());
         match result {
             Err(BarrelError::NonExportCode(_)) => {}
@@ -383,7 +648,7 @@ mod tests {
         module.body.push(ModuleItem::ModuleDecl(wildcard_export));
 
         // Validate the barrel file
-        let result = validate_barrel_file(&module);
+        let result = validate_barrel_file(&module).map_err(|violation| violation.error);
         assert!(result.is_err());
         match result {
             Err(BarrelError::WildcardExport(_)) => {}
@@ -403,7 +668,7 @@ mod tests {
             .push(ModuleItem::Stmt(Stmt::Empty(EmptyStmt { span: DUMMY_SP })));
 
         // Validate the barrel file
-        let result = validate_barrel_file(&module);
+        let result = validate_barrel_file(&module).map_err(|violation| violation.error);
         assert!(result.is_err());
         match result {
             Err(BarrelError::NonExportCode(_)) => {}
@@ -444,7 +709,7 @@ mod tests {
         module.body.push(ModuleItem::ModuleDecl(default_export));
 
         // Validate the barrel file
-        let result = validate_barrel_file(&module);
+        let result = validate_barrel_file(&module).map_err(|violation| violation.error);
         assert!(result.is_err());
         match result {
             Err(BarrelError::NonExportCode(_)) => {}
@@ -496,6 +761,7 @@ mod tests {
         assert_eq!(re_exports[0].source_path, "./components/Button");
         assert_eq!(re_exports[0].original_name, "Button");
         assert!(!re_exports[0].is_default);
+        assert!(!re_exports[0].is_type_only);
 
         // Create a barrel file AST with renamed exports
         let mut module = Module {
@@ -587,4 +853,256 @@ mod tests {
         assert_eq!(re_exports[0].original_name, "default");
         assert!(re_exports[0].is_default);
     }
+
+    #[test]
+    fn test_violation_span() {
+        let wildcard_span = Span::new(BytePos(40), BytePos(75));
+
+        let module = Module {
+            span: DUMMY_SP,
+            body: vec![
+                ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                    span: Span::new(BytePos(1), BytePos(39)),
+                    specifiers: vec![],
+                    src: Some(Box::new(Str {
+                        span: DUMMY_SP,
+                        value: "./components".into(),
+                        raw: None,
+                    })),
+                    type_only: false,
+                    with: None,
+                })),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll {
+                    span: wildcard_span,
+                    src: Box::new(Str {
+                        span: DUMMY_SP,
+                        value: "./components".into(),
+                        raw: None,
+                    }),
+                    with: None,
+                    type_only: false,
+                })),
+            ],
+            shebang: None,
+        };
+
+        let violation = analyze_barrel_file(&module, "/path/to/barrel/index.ts").unwrap_err();
+        assert_eq!(violation.span, wildcard_span);
+        assert!(matches!(violation.error, BarrelError::WildcardExport(_)));
+    }
+
+    /// Creates `export { orig as exported } from 'source'` with the given specifier span
+    fn named_re_export(orig: &str, exported: &str, source: &str, lo: u32) -> ModuleItem {
+        let ident = |sym: &str| {
+            ModuleExportName::Ident(Ident {
+                span: DUMMY_SP,
+                sym: sym.into(),
+                optional: false,
+                ctxt: Default::default(),
+            })
+        };
+
+        ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+            span: DUMMY_SP,
+            specifiers: vec![ExportSpecifier::Named(ExportNamedSpecifier {
+                span: Span::new(BytePos(lo), BytePos(lo + 10)),
+                orig: ident(orig),
+                exported: Some(ident(exported)),
+                is_type_only: false,
+            })],
+            src: Some(Box::new(Str {
+                span: DUMMY_SP,
+                value: source.into(),
+                raw: None,
+            })),
+            type_only: false,
+            with: None,
+        }))
+    }
+
+    #[test]
+    fn test_duplicate_exports() {
+        let module = Module {
+            span: DUMMY_SP,
+            body: vec![
+                named_re_export("Button", "Button", "./Button", 1),
+                named_re_export("Card", "Card", "./Card", 20),
+                named_re_export("IconButton", "Button", "./IconButton", 40),
+                named_re_export("Card", "Card", "./Card", 60),
+            ],
+            shebang: None,
+        };
+
+        // The first duplicate is reported at its specifier
+        let violation = analyze_barrel_file(&module, "/path/to/barrel/index.ts").unwrap_err();
+        assert_eq!(violation.span, Span::new(BytePos(40), BytePos(50)));
+        assert_eq!(
+            violation.error,
+            BarrelError::DuplicateExport(
+                "'Button' is exported from './Button' and './IconButton'".into()
+            )
+        );
+
+        // All duplicates are found, even when they point to the same export
+        let (re_exports, violations) = find_violations(&module);
+        assert_eq!(re_exports.len(), 4);
+        assert_eq!(
+            violations
+                .iter()
+                .map(|violation| violation.span.lo)
+                .collect::<Vec<_>>(),
+            vec![BytePos(40), BytePos(60)]
+        );
+        assert_eq!(violations[1].error.kind(), "duplicate-export");
+
+        // Renaming resolves the conflict
+        let module = Module {
+            span: DUMMY_SP,
+            body: vec![
+                named_re_export("Button", "Button", "./Button", 1),
+                named_re_export("Button", "IconButton", "./IconButton", 20),
+            ],
+            shebang: None,
+        };
+        assert_eq!(
+            analyze_barrel_file(&module, "/path/to/barrel/index.ts")
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_exported_names() {
+        let names = |code: &str| {
+            let cm: Lrc<SourceMap> = Default::default();
+            let fm = cm.new_source_file(Lrc::new(FileName::Anon), code.to_string());
+            let module = parse_file_as_module(
+                &fm,
+                Syntax::Typescript(Default::default()),
+                Default::default(),
+                None,
+                &mut vec![],
+            )
+            .unwrap();
+
+            exported_names(&module).map(|names| {
+                let mut names: Vec<String> = names.into_iter().collect();
+                names.sort();
+                names
+            })
+        };
+
+        assert_eq!(
+            names(
+                r#"
+                import { helper } from "./helper";
+                export const [first, { second, third: renamed }, ...rest] = helper();
+                export function run() {}
+                export class Store {}
+                export interface Props {}
+                export type Id = string;
+                export enum Color { Red }
+                export { helper as util, helper as "kebab-name" };
+                export * as utils from "./utils";
+                export default run;
+                "#
+            ),
+            Some(
+                [
+                    "Color",
+                    "Id",
+                    "Props",
+                    "Store",
+                    "default",
+                    "first",
+                    "kebab-name",
+                    "renamed",
+                    "rest",
+                    "run",
+                    "second",
+                    "util",
+                    "utils",
+                ]
+                .iter()
+                .map(|name| name.to_string())
+                .collect()
+            )
+        );
+
+        // Exports that cannot be listed statically
+        assert_eq!(names("export * from './ui';\nexport const a = 1;"), None);
+        assert_eq!(names("module.exports = { a: 1 };"), None);
+    }
+
+    #[test]
+    fn test_default_re_exports() {
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(
+            Lrc::new(FileName::Anon),
+            r#"
+            export { default } from "./Button";
+            export { default as Card } from "./Card";
+            export { default as "card-list" } from "./CardList";
+            export { Input as Field } from "./Input";
+            export { Link as "nav-link", "icon-name" as Icon, "a-b" } from "./names";
+            export type { default as Props } from "./Props";
+            "#
+            .to_string(),
+        );
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::Typescript(Default::default()),
+            Default::default(),
+            None,
+            &mut vec![],
+        )
+        .unwrap();
+
+        let re_exports = analyze_barrel_file(&module, "/path/to/barrel/index.ts").unwrap();
+        let mappings: Vec<_> = re_exports
+            .iter()
+            .map(|re_export| {
+                (
+                    re_export.exported_name.as_str(),
+                    re_export.original_name.as_str(),
+                    re_export.is_default,
+                    re_export.is_type_only,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            mappings,
+            vec![
+                ("default", "default", true, false),
+                ("Card", "default", true, false),
+                ("card-list", "default", true, false),
+                ("Field", "Input", false, false),
+                ("nav-link", "Link", false, false),
+                ("Icon", "icon-name", false, false),
+                ("a-b", "a-b", false, false),
+                ("Props", "default", true, true),
+            ]
+        );
+
+        // A named export re-exported as the default export of the barrel file
+        let fm = cm.new_source_file(
+            Lrc::new(FileName::Anon),
+            r#"export { Input as default } from "./Input";"#.to_string(),
+        );
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::Typescript(Default::default()),
+            Default::default(),
+            None,
+            &mut vec![],
+        )
+        .unwrap();
+
+        let re_exports = analyze_barrel_file(&module, "/path/to/barrel/index.ts").unwrap();
+        assert_eq!(re_exports[0].exported_name, "default");
+        assert_eq!(re_exports[0].original_name, "Input");
+        assert!(!re_exports[0].is_default);
+    }
 }
@@ -0,0 +1,150 @@
+//! Report module for the barrel files plugin
+//!
+//! This module records what happened to the barrel imports of a source file and appends
+//! the records to a JSON Lines file, so the outcome of a whole build can be audited.
+
+use serde::Serialize;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+/// Outcome of processing a barrel import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStatus {
+    /// The import was replaced with direct imports
+    Rewritten,
+    /// The import was left unchanged
+    Skipped,
+    /// The import could not be processed and failed the build
+    Error,
+}
+
+/// Record of a barrel import in the report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReportRecord {
+    /// The source file, relative to the current working directory
+    pub file: String,
+
+    /// The import path as written in the source file
+    pub specifier: String,
+
+    /// The barrel file the import resolved to, relative to the current working directory
+    pub barrel_file: Option<String>,
+
+    /// The outcome of processing the import
+    pub status: ImportStatus,
+
+    /// The import paths of the import declarations replacing the import
+    pub imports: Vec<String>,
+
+    /// The error for which the import was skipped or failed
+    pub reason: Option<String>,
+
+    /// The code of the error for which the import was skipped or failed
+    pub code: Option<&'static str>,
+}
+
+/// Records of the barrel imports of a source file
+#[derive(Debug)]
+pub struct Report {
+    /// Virtual path to the report file
+    path: String,
+
+    /// Records in the order of the imports
+    records: Vec<ReportRecord>,
+}
+
+impl Report {
+    /// Creates an empty report to be appended to the given file
+    pub fn new(path: String) -> Self {
+        Self {
+            path,
+            records: Vec::new(),
+        }
+    }
+
+    /// Adds a record to the report
+    pub fn add(&mut self, record: ReportRecord) {
+        self.records.push(record);
+    }
+
+    /// Appends the records to the report file, one JSON object per line
+    ///
+    /// The records are written with a single write to a file opened in append mode, so the
+    /// records of files transformed in parallel do not interleave.
+    pub fn write(&self) -> io::Result<()> {
+        if self.records.is_empty() {
+            return Ok(());
+        }
+
+        let mut content = String::new();
+        for record in &self.records {
+            content.push_str(&serde_json::to_string(record)?);
+            content.push('\n');
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        file.write_all(content.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(specifier: &str, status: ImportStatus) -> ReportRecord {
+        ReportRecord {
+            file: "src/pages/home.ts".to_string(),
+            specifier: specifier.to_string(),
+            barrel_file: Some("src/features/user/index.ts".to_string()),
+            status,
+            imports: Vec::new(),
+            reason: None,
+            code: None,
+        }
+    }
+
+    #[test]
+    fn test_append_records() {
+        let path = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-report-{}.jsonl",
+            std::process::id()
+        ));
+        let path = path.to_string_lossy().to_string();
+        let _ = std::fs::remove_file(&path);
+
+        let mut report = Report::new(path.clone());
+        report.add(ReportRecord {
+            imports: vec!["../features/user/ui/UserCard".to_string()],
+            ..record("#features/user", ImportStatus::Rewritten)
+        });
+        report.write().unwrap();
+
+        let mut report = Report::new(path.clone());
+        report.add(ReportRecord {
+            reason: Some("E_NO_NAMESPACE_IMPORTS: Namespace imports are not supported for barrel file optimization".to_string()),
+            code: Some("E_NO_NAMESPACE_IMPORTS"),
+            ..record("#features/user", ImportStatus::Skipped)
+        });
+        report.write().unwrap();
+
+        // Empty reports do not create the file
+        Report::new(format!("{}.empty", path)).write().unwrap();
+        assert!(!std::path::Path::new(&format!("{}.empty", path)).exists());
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            content.lines().collect::<Vec<_>>(),
+            vec![
+                r##"{"file":"src/pages/home.ts","specifier":"#features/user","barrel_file":"src/features/user/index.ts","status":"rewritten","imports":["../features/user/ui/UserCard"],"reason":null,"code":null}"##,
+                r##"{"file":"src/pages/home.ts","specifier":"#features/user","barrel_file":"src/features/user/index.ts","status":"skipped","imports":[],"reason":"E_NO_NAMESPACE_IMPORTS: Namespace imports are not supported for barrel file optimization","code":"E_NO_NAMESPACE_IMPORTS"}"##,
+            ]
+        );
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
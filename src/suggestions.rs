@@ -0,0 +1,109 @@
+//! Suggestion module for the barrel files plugin
+//!
+//! This module finds names similar to an imported name that could not be resolved,
+//! to point out typos and wrong casing in imports from barrel files.
+
+/// Maximum number of suggested names
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Calculates the Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+/// Finds the candidates most similar to a name
+///
+/// Candidates differing from the name only in case go first, followed by candidates within
+/// an edit distance of a third of the name length, closest first.
+///
+/// # Arguments
+///
+/// * `name` - The name that could not be resolved
+/// * `candidates` - The names available instead
+///
+/// # Returns
+///
+/// Up to three similar names, most similar first
+pub fn similar_names<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<&'a str> {
+    let max_distance = (name.chars().count() / 3).max(1);
+    let lowercase_name = name.to_lowercase();
+
+    let mut scored: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .filter_map(|candidate| {
+            if candidate.to_lowercase() == lowercase_name {
+                return Some((0, candidate));
+            }
+
+            let distance = edit_distance(name, candidate);
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect();
+
+    // The stable sort keeps the order of candidates with the same distance
+    scored.sort_by_key(|(distance, _)| *distance);
+
+    let mut names: Vec<&str> = Vec::new();
+    for (_, candidate) in scored {
+        if !names.contains(&candidate) {
+            names.push(candidate);
+        }
+    }
+    names.truncate(MAX_SUGGESTIONS);
+
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("Button", "Button"), 0);
+        assert_eq!(edit_distance("Buton", "Button"), 1);
+        assert_eq!(edit_distance("Butten", "Button"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_similar_names() {
+        let candidates = ["Button", "ButtonGroup", "userCard", "Input", "select"];
+
+        // Typos
+        assert_eq!(similar_names("Buton", candidates), vec!["Button"]);
+
+        // Wrong casing goes first
+        assert_eq!(similar_names("UserCard", candidates), vec!["userCard"]);
+        assert_eq!(similar_names("Select", candidates), vec!["select"]);
+
+        // Names too different from any candidate
+        assert!(similar_names("Action", candidates).is_empty());
+
+        // Duplicates are skipped and the number of suggestions is limited
+        assert_eq!(
+            similar_names("ab", ["aa", "ab1", "ac", "aa", "ad"]),
+            vec!["aa", "ab1", "ac"]
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Validation module for the barrel files plugin
+//!
+//! This module checks barrel files for lint reports. Unlike the analysis used by the
+//! transform, which stops at the first offending item, it collects every violation of a
+//! barrel file, including names exported more than once and re-exports of modules that
+//! do not exist.
+
+use crate::config::Config;
+use crate::errors::{BarrelPluginError, SourceLocation};
+use crate::import_transformer::{parse_file, source_location};
+use crate::manifest::{find_barrel_files, relative_to};
+use crate::paths::{dirname, path_join, resolve_source_file};
+use crate::re_export::{find_violations, BarrelError};
+use std::collections::BTreeMap;
+use swc_core::ecma::parser::Syntax;
+
+/// Violation of the barrel file rules found by the validation
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    /// The violated rule
+    pub error: BarrelError,
+
+    /// The position of the offending item in the barrel file
+    pub location: Option<SourceLocation>,
+}
+
+/// Validates a barrel file
+///
+/// Only relative re-export sources are checked for existence, as package and alias
+/// sources cannot be resolved from the barrel file alone.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the barrel file
+///
+/// # Returns
+///
+/// All violations in the order of the items of the barrel file, `Err` if the file cannot
+/// be read or parsed
+pub fn validate_barrel(file_path: &str) -> Result<Vec<Violation>, BarrelPluginError> {
+    Ok(check_barrel(file_path)?.1)
+}
+
+/// Validates a barrel file, also counting its valid re-exports
+fn check_barrel(file_path: &str) -> Result<(usize, Vec<Violation>), BarrelPluginError> {
+    let (ast, cm) = parse_file(file_path, Syntax::Typescript(Default::default()))?;
+    let (re_exports, violations) = find_violations(&ast);
+
+    let mut violations: Vec<_> = violations
+        .into_iter()
+        .map(|violation| (violation.span, violation.error))
+        .collect();
+
+    let mut checked_sources = Vec::new();
+    for (re_export, span) in &re_exports {
+        let source_path = &re_export.source_path;
+
+        if !source_path.starts_with('.') || checked_sources.contains(source_path) {
+            continue;
+        }
+        checked_sources.push(source_path.clone());
+
+        if resolve_source_file(&path_join(&dirname(file_path), source_path)).is_none() {
+            violations.push((*span, BarrelError::UnresolvedSource(source_path.clone())));
+        }
+    }
+
+    violations.sort_by_key(|(span, _)| span.lo);
+
+    let violations = violations
+        .into_iter()
+        .map(|(span, error)| Violation {
+            error,
+            location: source_location(&cm, file_path, span),
+        })
+        .collect();
+
+    Ok((re_exports.len(), violations))
+}
+
+/// Validates the barrel files matched by the patterns of a configuration
+///
+/// # Arguments
+///
+/// * `config` - The plugin configuration
+/// * `cwd` - The current working directory the patterns are relative to
+///
+/// # Returns
+///
+/// The violations of each barrel file by its path relative to `cwd`, or the error if it
+/// cannot be read, parsed or has no re-exports. `Err` if a pattern cannot be compiled.
+pub fn validate_barrel_files(
+    config: &Config,
+    cwd: &str,
+) -> Result<BTreeMap<String, Result<Vec<Violation>, BarrelPluginError>>, BarrelPluginError> {
+    let mut barrels = BTreeMap::new();
+
+    for file in find_barrel_files(config, cwd)? {
+        let validation = match check_barrel(&file) {
+            Ok((0, violations)) if violations.is_empty() => Err(BarrelPluginError::NoReExports {
+                barrel_file: file.clone(),
+            }),
+            Ok((_, violations)) => Ok(violations),
+            Err(error) => Err(error),
+        };
+
+        barrels.insert(relative_to(cwd, &file), validation);
+    }
+
+    Ok(barrels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_validate_barrel() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-validation-{}",
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("ui")).unwrap();
+        fs::write(dir.join("ui/Button.tsx"), "export const Button = 1;").unwrap();
+        fs::write(dir.join("ui/Card.ts"), "export const Card = 1;").unwrap();
+
+        let barrel = dir.join("index.ts").to_string_lossy().to_string();
+        fs::write(
+            &barrel,
+            r#"export { Button } from './ui/Button';
+export * from './ui';
+const version = 1;
+export { Card as Button, Card } from './ui/Card.js';
+export { Input } from './ui/Input';
+export { Link } from 'router';
+"#,
+        )
+        .unwrap();
+
+        let violations = validate_barrel(&barrel).unwrap();
+        let summary: Vec<_> = violations
+            .iter()
+            .map(|violation| {
+                let location = violation.location.as_ref().unwrap();
+                (location.line, location.column, violation.error.kind())
+            })
+            .collect();
+
+        assert_eq!(
+            summary,
+            vec![
+                (2, 1, "wildcard-export"),
+                (3, 1, "non-export-code"),
+                (4, 10, "duplicate-export"),
+                (5, 10, "unresolved-source"),
+            ]
+        );
+        assert_eq!(
+            violations[2].error,
+            BarrelError::DuplicateExport(
+                "'Button' is exported from './ui/Button' and './ui/Card.js'".into()
+            )
+        );
+
+        // Valid barrel files have no violations
+        fs::write(&barrel, "export { Button } from './ui/Button';").unwrap();
+        assert_eq!(validate_barrel(&barrel).unwrap(), vec![]);
+
+        fs::write(&barrel, "export {").unwrap();
+        assert!(matches!(
+            validate_barrel(&barrel),
+            Err(BarrelPluginError::FileParse { .. })
+        ));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
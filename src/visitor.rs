@@ -1,17 +1,39 @@
 use std::collections::HashMap;
 use std::path::Path;
+use std::sync::Arc;
+use swc_core::common::comments::{Comment, Comments};
+use swc_core::common::{BytePos, Span, Spanned};
 use swc_core::ecma::ast::{ImportDecl, ImportSpecifier, Module, ModuleItem};
 use swc_core::ecma::visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 use crate::alias_resolver::AliasResolver;
-use crate::config::Config;
-use crate::import_transformer::transform_import;
+use crate::compiled_config::CompiledConfig;
+use crate::config::{
+    BarrelOptions, Config, DeepImportMode, DiagnosticsOutput, MissingBarrelMode, OutputExtension,
+    OutputPathStyle, SelfImportMode, TransformMode,
+};
+use crate::errors::{BarrelPluginError, ImportIssues, Warning};
+use crate::import_merger::merge_imports;
+use crate::import_transformer::{format_import, transform_import, BarrelReader};
+use crate::manifest::{load_manifest, LoadedManifest};
 use crate::path_resolver::PathResolver;
-use crate::paths::{dirname, path_join};
-use crate::pattern_matcher::CompiledPattern;
+use crate::paths::{dirname, file_exists, path_join, resolve_module_file, resolve_relative_path};
+use crate::report::{ImportStatus, Report, ReportRecord};
+
+/// Comment at the top of a file disabling the transform for the whole file
+const DISABLE_FILE_PRAGMA: &str = "barrel-files-disable";
+
+/// Comment before an import disabling the transform for this import
+const DISABLE_NEXT_LINE_PRAGMA: &str = "barrel-files-disable-next-line";
 
 /// Visitor for transforming barrel file imports
-pub struct BarrelTransformVisitor {
+pub struct BarrelTransformVisitor<C: Comments> {
+    /// Comments of the current file, used to read the pragma comments
+    comments: Option<C>,
+
+    /// Virtual path to the current file
+    source_file: String,
+
     /// Virtual path to the directory for the current file
     source_dir: String,
 
@@ -25,26 +47,129 @@ pub struct BarrelTransformVisitor {
     /// Resolver for file paths
     path_resolver: PathResolver,
 
-    /// Pre-compiled patterns for barrel files
-    compiled_patterns: Vec<CompiledPattern>,
+    /// Patterns for barrel files and other settings compiled from the configuration
+    compiled_config: Arc<CompiledConfig>,
+
+    /// How to handle imports matched by aliases or patterns without an existing barrel file
+    missing_barrel_mode: MissingBarrelMode,
+
+    /// How to report imports of barrel files in a directory containing the current file
+    self_import_mode: SelfImportMode,
+
+    /// How to report imports bypassing a barrel file
+    deep_import_mode: DeepImportMode,
+
+    /// Extension of the import paths pointing to the source modules
+    output_extension: OutputExtension,
+
+    /// Virtual path to the directory in which the analyses of barrel files are persisted
+    cache_dir: Option<String>,
+
+    /// Manifest with the precomputed re-exports of barrel files
+    manifest: Option<Arc<LoadedManifest>>,
+
+    /// Merge rewritten imports with other imports of the same module
+    merge_imports: bool,
+
+    /// Whether to rewrite barrel imports or only report them
+    mode: TransformMode,
 
     /// Enable debug logging
     debug: bool,
 
-    /// Plugin configuration
-    config: Config,
+    /// Where to report warnings and debug logs
+    diagnostics_output: DiagnosticsOutput,
+
+    /// Report of the barrel imports of the current file, if enabled
+    report: Option<Report>,
+}
+
+fn log(diagnostics_output: DiagnosticsOutput, message: String) {
+    let message = format!("[swc-plugin-barrel-files] {}", message);
+
+    match diagnostics_output {
+        DiagnosticsOutput::Swc => swc_core::common::errors::HANDLER.with(|handler| {
+            handler.note_without_error(&message);
+        }),
+        DiagnosticsOutput::Console => println!("{}", message),
+    }
 }
 
-fn log(message: String) {
-    println!("[swc-plugin-barrel-files] {}", message);
+fn warn(diagnostics_output: DiagnosticsOutput, span: Span, warning: &Warning) {
+    match diagnostics_output {
+        DiagnosticsOutput::Swc => swc_core::common::errors::HANDLER.with(|handler| {
+            let mut diagnostic = handler.struct_span_warn(span, &warning.message);
+
+            if let Some(label) = &warning.label {
+                diagnostic.span_label(span, label);
+            }
+
+            for note in &warning.notes {
+                diagnostic.note(note);
+            }
+
+            diagnostic.emit()
+        }),
+        DiagnosticsOutput::Console => eprintln!("Warning: {}", warning),
+    }
 }
 
-impl BarrelTransformVisitor {
-    /// Creates a new visitor with the specified configuration
-    pub fn new(config: &Config, cwd: String, filename: String) -> Result<Option<Self>, String> {
-        let path_resolver = PathResolver::new(&config.symlinks, &cwd);
+fn error(span: Span, error: &BarrelPluginError) {
+    swc_core::common::errors::HANDLER.with(|handler| {
+        let mut diagnostic =
+            handler.struct_span_err(span, &format!("Error processing barrel import: {}", error));
 
-        let compiled_patterns = Self::compile_patterns(&cwd, config, &path_resolver)?;
+        if let Some(label) = error.label() {
+            diagnostic.span_label(span, &label);
+        }
+
+        for note in error.notes() {
+            diagnostic.note(&note);
+        }
+
+        diagnostic.emit()
+    });
+}
+
+/// Checks whether a comment is the given pragma, e.g. `// barrel-files-disable`
+/// or `/* barrel-files-disable */`
+fn is_pragma(comment: &Comment, pragma: &str) -> bool {
+    comment.text.trim_start_matches('*').trim() == pragma
+}
+
+impl<C: Comments> BarrelTransformVisitor<C> {
+    /// Creates a new visitor with the specified configuration for the WASM plugin
+    #[cfg(feature = "plugin")]
+    pub fn new(
+        config: &Config,
+        cwd: String,
+        filename: String,
+        comments: Option<C>,
+    ) -> Result<Option<Self>, BarrelPluginError> {
+        Self::with_fs_root(
+            config,
+            cwd,
+            crate::path_resolver::SWC_VIRTUAL_FS_ROOT_DIR,
+            filename,
+            comments,
+        )
+    }
+
+    /// Creates a new visitor reading files with the current working directory mounted at
+    /// the given root
+    ///
+    /// The WASM plugin sees the current working directory at `/cwd`, while native tools use
+    /// the current working directory itself.
+    pub fn with_fs_root(
+        config: &Config,
+        cwd: String,
+        fs_root: &str,
+        filename: String,
+        comments: Option<C>,
+    ) -> Result<Option<Self>, BarrelPluginError> {
+        let path_resolver = PathResolver::new(&config.symlinks, &cwd).with_root(fs_root);
+
+        let compiled_config = CompiledConfig::get(config, &cwd, fs_root, &path_resolver)?;
 
         // Normalize absolute path to the source file
         // swc/loader and swc/jest pass full `filepath`
@@ -56,17 +181,27 @@ impl BarrelTransformVisitor {
         let source_file_path = path_join(&cwd, &source_file_path);
 
         // Cannot process files outside cwd due to WASM restrictions
-        if !source_file_path.starts_with(&cwd) {
+        if !path_resolver.is_accessible(&source_file_path) {
             if config.debug.unwrap_or_default() {
-                log(format!(
-                    "Skipping {} (reason: outside cwd)",
-                    source_file_path
-                ));
+                log(
+                    config.diagnostics_output,
+                    format!("Skipping {} (reason: outside cwd)", source_file_path),
+                );
             }
             return Ok(None);
         }
 
         let source_file_virtual_path = path_resolver.to_virtual_path(&source_file_path)?;
+
+        if let Some(reason) = compiled_config.exclusion_reason(&source_file_virtual_path) {
+            if config.debug.unwrap_or_default() {
+                log(
+                    config.diagnostics_output,
+                    format!("Skipping {} (reason: {})", source_file_virtual_path, reason),
+                );
+            }
+            return Ok(None);
+        }
         let source_dir = dirname(&source_file_virtual_path);
 
         let alias_resolver = AliasResolver::new(
@@ -76,14 +211,52 @@ impl BarrelTransformVisitor {
             &source_file_virtual_path,
         )?;
 
+        let report = match &config.report {
+            Some(report_path) => {
+                let report_path = path_join(&cwd, report_path);
+                Some(Report::new(path_resolver.to_virtual_path(&report_path)?))
+            }
+            None => None,
+        };
+
+        let cache_dir = match &config.cache_dir {
+            Some(cache_dir) => {
+                let cache_dir = path_join(&cwd, cache_dir);
+                Some(path_resolver.to_virtual_path(&cache_dir)?)
+            }
+            None => None,
+        };
+
+        let manifest = match &config.manifest {
+            Some(manifest_path) => {
+                let manifest_path = path_join(&cwd, manifest_path);
+                let manifest_path = path_resolver.to_virtual_path(&manifest_path)?;
+                Some(load_manifest(&manifest_path, &|barrel_file| {
+                    path_resolver.to_virtual_path(&path_join(&cwd, barrel_file))
+                })?)
+            }
+            None => None,
+        };
+
         let visitor = Self {
+            comments,
+            source_file: source_file_virtual_path.clone(),
             source_dir,
             import_replacements: HashMap::new(),
             alias_resolver,
             path_resolver,
-            compiled_patterns,
+            compiled_config,
+            cache_dir,
+            manifest,
+            missing_barrel_mode: config.missing_barrel_mode,
+            self_import_mode: config.self_import_mode,
+            deep_import_mode: config.deep_import_mode,
+            output_extension: config.output_extension.clone(),
+            merge_imports: config.merge_imports.unwrap_or_default(),
+            mode: config.mode,
             debug: config.debug.unwrap_or_default(),
-            config: config.to_owned(),
+            diagnostics_output: config.diagnostics_output,
+            report,
         };
 
         visitor.log(format!("Parsing {}", source_file_virtual_path));
@@ -91,57 +264,49 @@ impl BarrelTransformVisitor {
         Ok(Some(visitor))
     }
 
-    fn compile_patterns(
-        cwd: &str,
-        config: &Config,
-        path_resolver: &PathResolver,
-    ) -> Result<Vec<CompiledPattern>, String> {
-        let mut compiled_patterns = Vec::new();
-
-        for pattern in &config.patterns {
-            let joined_path = path_join(cwd, pattern);
-            let virtual_path = path_resolver.to_virtual_path(&joined_path)?;
-
-            let compiled_pattern = CompiledPattern::new(&virtual_path)
-                .map_err(|e| format!("Failed to compile pattern '{}': {}", virtual_path, e))?;
-
-            compiled_patterns.push(compiled_pattern);
+    fn resolve_barrel_file(
+        &self,
+        import_path: &str,
+        issues: &mut ImportIssues,
+    ) -> Result<Option<(String, BarrelOptions)>, BarrelPluginError> {
+        if !import_path.starts_with('.') && !Path::new(import_path).is_absolute() {
+            self.resolve_aliased_import(import_path, issues)
+        } else {
+            self.resolve_local_import(import_path, issues)
         }
-
-        Ok(compiled_patterns)
     }
 
-    fn process_import(&self, import_decl: &ImportDecl) -> Result<Option<Vec<ImportDecl>>, String> {
-        let import_path = import_decl.src.value.as_str();
-
-        let barrel_file = if !import_path.starts_with('.') && !Path::new(import_path).is_absolute()
-        {
-            self.resolve_aliased_import(import_path)?
-        } else {
-            self.resolve_local_import(import_path)?
-        };
-
-        if let Some(barrel_file) = barrel_file {
-            self.transform_import(import_decl, &barrel_file)
-        } else {
-            Ok(None)
+    fn resolve_aliased_import(
+        &self,
+        import_path: &str,
+        issues: &mut ImportIssues,
+    ) -> Result<Option<(String, BarrelOptions)>, BarrelPluginError> {
+        if let Some((selected, competing)) = self.alias_resolver.find_ambiguity(import_path) {
+            issues
+                .warnings
+                .push(Warning::from(BarrelPluginError::AmbiguousAlias {
+                    import_path: import_path.to_string(),
+                    selected: selected.to_string(),
+                    competing: competing.to_string(),
+                }));
         }
-    }
 
-    fn resolve_aliased_import(&self, import_path: &str) -> Result<Option<String>, String> {
-        match self.alias_resolver.resolve(import_path)? {
+        match self
+            .alias_resolver
+            .resolve(import_path, self.missing_barrel_mode, issues)?
+        {
             Some(resolved_path) => {
                 self.log(format!(
                     "    alias \"{}\" resolved to {}",
                     import_path, resolved_path
                 ));
 
-                if !self.match_pattern(&resolved_path) {
+                let Some(options) = self.match_pattern(&resolved_path) else {
                     self.log(format!("    not matched by patterns: {}", resolved_path));
                     return Ok(None);
-                }
+                };
 
-                Ok(Some(resolved_path))
+                Ok(Some((resolved_path, options)))
             }
             None => {
                 self.log(format!("    import \"{}\" was not resolved", import_path));
@@ -151,7 +316,11 @@ impl BarrelTransformVisitor {
         }
     }
 
-    fn resolve_local_import(&self, import_path: &str) -> Result<Option<String>, String> {
+    fn resolve_local_import(
+        &self,
+        import_path: &str,
+        issues: &mut ImportIssues,
+    ) -> Result<Option<(String, BarrelOptions)>, BarrelPluginError> {
         let import_path = if import_path.starts_with(".") {
             path_join(&self.source_dir, import_path)
         } else {
@@ -171,23 +340,55 @@ impl BarrelTransformVisitor {
             }
         };
 
-        if !self.match_pattern(&barrel_file) {
+        let Some(options) = self.match_pattern(&barrel_file) else {
             self.log(format!("    not matched by patterns: {}", barrel_file));
             return Ok(None);
+        };
+
+        if !file_exists(&barrel_file) {
+            let error = BarrelPluginError::BarrelFileNotFound {
+                barrel_file: barrel_file.clone(),
+            };
+
+            return match self.missing_barrel_mode {
+                MissingBarrelMode::Error => Err(error),
+                MissingBarrelMode::Warn => {
+                    issues.skip(error, true);
+                    Ok(None)
+                }
+                MissingBarrelMode::Off => {
+                    self.log(format!("    barrel file does not exist: {}", barrel_file));
+                    issues.skip(error, false);
+                    Ok(None)
+                }
+            };
         }
 
-        Ok(Some(barrel_file))
+        Ok(Some((barrel_file, options)))
     }
 
     fn transform_import(
         &self,
         import_decl: &ImportDecl,
         barrel_file: &str,
-    ) -> Result<Option<Vec<ImportDecl>>, String> {
+        options: &BarrelOptions,
+        issues: &mut ImportIssues,
+    ) -> Result<Option<Vec<ImportDecl>>, BarrelPluginError> {
         self.log(format!("    found barrel file: {}", barrel_file));
 
-        let new_imports =
-            transform_import(&self.source_dir, import_decl, barrel_file, &self.config)?;
+        let new_imports = transform_import(
+            import_decl,
+            barrel_file,
+            options,
+            &self.output_extension,
+            &BarrelReader {
+                match_barrel: &|path| self.match_pattern(path),
+                cache_dir: self.cache_dir.as_deref(),
+                manifest: self.manifest.as_deref(),
+            },
+            &|path| self.output_path(path, options.output_path_style),
+            issues,
+        )?;
 
         if let Some(new_imports) = new_imports {
             if self.debug {
@@ -223,31 +424,326 @@ impl BarrelTransformVisitor {
     ///
     /// # Returns
     ///
-    /// `true` if any pattern matches, `false` otherwise
-    fn match_pattern(&self, import_path: &str) -> bool {
-        self.compiled_patterns
-            .iter()
-            .any(|compiled_pattern| compiled_pattern.matches(import_path))
+    /// The settings of the first matching pattern if any pattern matches, `None` otherwise
+    fn match_pattern(&self, import_path: &str) -> Option<BarrelOptions> {
+        self.compiled_config
+            .patterns
+            .find(import_path)
+            .map(|index| self.compiled_config.pattern_options[index])
+    }
+
+    /// Formats the path to a source module according to the output path style
+    ///
+    /// # Arguments
+    ///
+    /// * `module_path` - The virtual path of the source module
+    /// * `output_path_style` - The output path style of the pattern matching the barrel file
+    ///
+    /// # Returns
+    ///
+    /// The import path to write into the rewritten import declaration
+    fn output_path(
+        &self,
+        module_path: &str,
+        output_path_style: OutputPathStyle,
+    ) -> Result<String, BarrelPluginError> {
+        // Native embedders pass real filesystem paths, which may not share a root with the
+        // source file (e.g. on different Windows drives)
+        let relative_path = || {
+            resolve_relative_path(&self.source_dir, module_path).ok_or_else(|| {
+                BarrelPluginError::InvalidFilePath {
+                    path: module_path.to_string(),
+                }
+            })
+        };
+
+        match output_path_style {
+            OutputPathStyle::Relative => relative_path(),
+            OutputPathStyle::Alias => match self.alias_resolver.to_alias(module_path)? {
+                Some(alias_path) => Ok(alias_path),
+                None => relative_path(),
+            },
+            OutputPathStyle::AbsoluteFromRoot => Ok(self.path_resolver.to_root_path(module_path)),
+        }
+    }
+
+    /// Adds the outcome of processing an import to the report, if enabled
+    ///
+    /// Only imports of barrel files, and imports skipped or failed while looking for one, are
+    /// recorded.
+    fn record_import(
+        &mut self,
+        import_path: &str,
+        barrel_file: Option<&str>,
+        result: &Result<Option<Vec<ImportDecl>>, BarrelPluginError>,
+        issues: &ImportIssues,
+    ) {
+        let Some(report) = &mut self.report else {
+            return;
+        };
+
+        let new_imports = match result {
+            Ok(Some(new_imports)) => new_imports.as_slice(),
+            _ => &[],
+        };
+
+        let (status, error) = match result {
+            Err(err) => (ImportStatus::Error, Some(err)),
+            Ok(_) if !new_imports.is_empty() => (ImportStatus::Rewritten, None),
+            Ok(_) => (ImportStatus::Skipped, issues.skip_reason.as_ref()),
+        };
+
+        if barrel_file.is_none() && error.is_none() {
+            return;
+        }
+
+        report.add(ReportRecord {
+            file: self.path_resolver.to_cwd_relative_path(&self.source_file),
+            specifier: import_path.to_string(),
+            barrel_file: barrel_file.map(|path| self.path_resolver.to_cwd_relative_path(path)),
+            status,
+            imports: new_imports
+                .iter()
+                .map(|new_import| new_import.src.value.to_string())
+                .collect(),
+            reason: error.map(|error| error.to_string()),
+            code: error.map(|error| error.code()),
+        });
+    }
+
+    /// Reports the imports that would be rewritten, in the order of the module items,
+    /// without applying the replacements
+    fn report_replacements(&mut self, items: &[ModuleItem]) {
+        for item in items {
+            let ModuleItem::ModuleDecl(swc_core::ecma::ast::ModuleDecl::Import(import)) = item
+            else {
+                continue;
+            };
+
+            if let Some(replacements) = self.import_replacements.remove(&import.span.lo.0) {
+                let replacements: Vec<String> = replacements.iter().map(format_import).collect();
+
+                warn(
+                    self.diagnostics_output,
+                    import.span,
+                    &Warning::new(format!(
+                        "Import from {} would be rewritten to: {}",
+                        import.src.value,
+                        replacements.join("; ")
+                    )),
+                );
+            }
+        }
+    }
+
+    /// Checks whether the current file is inside a directory or its subdirectories
+    fn is_inside(&self, dir: &str) -> bool {
+        self.source_dir == dir || self.source_dir.starts_with(&format!("{}/", dir))
+    }
+
+    /// Finds the barrel file bypassed by an import that is not a barrel import
+    ///
+    /// Aliased imports are resolved by their longest prefix resolving to a barrel file, e.g.
+    /// `@modules/user/models/User` through `@modules/user`. For local imports, the directories
+    /// containing the imported module are searched for barrel files.
+    ///
+    /// # Arguments
+    ///
+    /// * `import_path` - The import path of the declaration
+    ///
+    /// # Returns
+    ///
+    /// The outermost bypassed barrel file whose directory does not contain the current file,
+    /// `None` if the import does not bypass any barrel file
+    fn bypassed_barrel_file(&self, import_path: &str) -> Option<String> {
+        let mut bypassed = None;
+
+        if !import_path.starts_with('.') && !Path::new(import_path).is_absolute() {
+            let mut prefix = import_path;
+
+            while let Some((parent, _)) = prefix.rsplit_once('/') {
+                prefix = parent;
+
+                let resolved = self.alias_resolver.resolve(
+                    prefix,
+                    MissingBarrelMode::Off,
+                    &mut ImportIssues::default(),
+                );
+
+                if let Ok(Some(barrel_file)) = resolved {
+                    if self.match_pattern(&barrel_file).is_some()
+                        && !self.is_inside(&dirname(&barrel_file))
+                    {
+                        bypassed = Some(barrel_file);
+                    }
+                }
+            }
+
+            return bypassed;
+        }
+
+        let module_path = path_join(&self.source_dir, import_path);
+        let module_path = self.path_resolver.resolve_path(&module_path);
+        let module_path = self.path_resolver.to_virtual_path(&module_path).ok()?;
+
+        let module_file = resolve_module_file(&module_path);
+
+        let mut dir = dirname(&module_path);
+        while !dir.is_empty() && dir != "/" {
+            for name in &self.compiled_config.barrel_file_names {
+                let barrel_file = format!("{}/{}", dir, name);
+
+                if module_file.as_ref() != Some(&barrel_file)
+                    && self.match_pattern(&barrel_file).is_some()
+                    && file_exists(&barrel_file)
+                    && !self.is_inside(&dir)
+                {
+                    bypassed = Some(barrel_file);
+                }
+            }
+
+            dir = dirname(&dir);
+        }
+
+        bypassed
+    }
+
+    /// Checks whether the current file imports a barrel file from inside its directory
+    ///
+    /// Such a barrel file usually re-exports the current file, directly or through other
+    /// modules, so importing through it creates a circular dependency.
+    ///
+    /// # Returns
+    ///
+    /// The error describing the self-import, `None` if the barrel file is outside of the
+    /// directories containing the current file
+    fn self_import(&self, barrel_file: &str) -> Option<BarrelPluginError> {
+        (self.is_inside(&dirname(barrel_file)) && self.source_file != barrel_file).then(|| {
+            BarrelPluginError::SelfImport {
+                source_file: self.source_file.clone(),
+                barrel_file: barrel_file.to_string(),
+            }
+        })
+    }
+
+    /// Checks whether the leading comments at a position contain the given pragma
+    fn has_pragma(&self, pos: BytePos, pragma: &str) -> bool {
+        self.comments.as_ref().is_some_and(|comments| {
+            comments.with_leading(pos, |comments| {
+                comments.iter().any(|comment| is_pragma(comment, pragma))
+            })
+        })
     }
 
     fn log(&self, message: String) {
         if self.debug {
-            log(message);
+            log(self.diagnostics_output, message);
         }
     }
 }
 
-impl VisitMut for BarrelTransformVisitor {
+impl<C: Comments> VisitMut for BarrelTransformVisitor<C> {
     // A comprehensive list of possible visitor methods can be found here:
     // https://rustdoc.swc.rs/swc_ecma_visit/trait.VisitMut.html
     noop_visit_mut_type!();
 
     fn visit_mut_module(&mut self, module: &mut Module) {
+        let file_start = module
+            .body
+            .first()
+            .map(|item| item.span().lo)
+            .unwrap_or(module.span.lo);
+
+        if self.has_pragma(file_start, DISABLE_FILE_PRAGMA) {
+            self.log(format!(
+                "Skipping {} (reason: {})",
+                self.source_file, DISABLE_FILE_PRAGMA
+            ));
+            return;
+        }
+
         module.visit_mut_children_with(self);
+
+        if let Some(report) = &self.report {
+            if let Err(err) = report.write() {
+                let handler = &swc_core::common::errors::HANDLER;
+                handler.with(|handler| {
+                    handler
+                        .struct_err(&format!("Error writing barrel import report: {}", err))
+                        .emit()
+                });
+            }
+        }
     }
 
     fn visit_mut_import_decl(&mut self, import_decl: &mut ImportDecl) {
-        match self.process_import(import_decl) {
+        let import_path = import_decl.src.value.to_string();
+
+        if self.has_pragma(import_decl.span.lo, DISABLE_NEXT_LINE_PRAGMA) {
+            self.log(format!(
+                "    skipping import \"{}\" (reason: {})",
+                import_path, DISABLE_NEXT_LINE_PRAGMA
+            ));
+            return;
+        }
+
+        let mut issues = ImportIssues::default();
+
+        let barrel_file = self.resolve_barrel_file(&import_path, &mut issues);
+        let resolved_barrel_file = match &barrel_file {
+            Ok(Some((barrel_file, _))) => Some(barrel_file.clone()),
+            _ => None,
+        };
+
+        let result = barrel_file.and_then(|barrel_file| match barrel_file {
+            Some((barrel_file, options)) => {
+                self.transform_import(import_decl, &barrel_file, &options, &mut issues)
+            }
+            None => Ok(None),
+        });
+
+        // Self-imports are still rewritten, which breaks the cycle through the barrel file
+        if let Some(self_import) = resolved_barrel_file
+            .as_deref()
+            .and_then(|barrel_file| self.self_import(barrel_file))
+        {
+            match self.self_import_mode {
+                SelfImportMode::Error => error(import_decl.span, &self_import),
+                SelfImportMode::Warn => issues.warnings.push(self_import.into()),
+                SelfImportMode::Off => {}
+            }
+        }
+
+        if self.deep_import_mode != DeepImportMode::Off
+            && resolved_barrel_file.is_none()
+            && result.is_ok()
+        {
+            if let Some(barrel_file) = self.bypassed_barrel_file(&import_path) {
+                let deep_import = BarrelPluginError::DeepImport {
+                    import_path: import_path.clone(),
+                    barrel_file,
+                };
+
+                match self.deep_import_mode {
+                    DeepImportMode::Error => error(import_decl.span, &deep_import),
+                    DeepImportMode::Warn => issues.warnings.push(deep_import.into()),
+                    DeepImportMode::Off => {}
+                }
+            }
+        }
+
+        for warning in &issues.warnings {
+            warn(self.diagnostics_output, import_decl.span, warning);
+        }
+
+        self.record_import(
+            &import_path,
+            resolved_barrel_file.as_deref(),
+            &result,
+            &issues,
+        );
+
+        match result {
             Ok(Some(new_imports)) => {
                 if !new_imports.is_empty() {
                     // Store the span of the original import as a key
@@ -258,17 +754,7 @@ impl VisitMut for BarrelTransformVisitor {
                 }
             }
             Ok(None) => {}
-            Err(err) => {
-                let handler = &swc_core::plugin::errors::HANDLER;
-                handler.with(|handler| {
-                    handler
-                        .struct_span_err(
-                            import_decl.span,
-                            &format!("Error processing barrel import: {}", err),
-                        )
-                        .emit()
-                });
-            }
+            Err(err) => error(import_decl.span, &err),
         }
 
         import_decl.visit_mut_children_with(self);
@@ -280,6 +766,11 @@ impl VisitMut for BarrelTransformVisitor {
             item.visit_mut_with(self);
         }
 
+        if self.mode == TransformMode::Check {
+            self.report_replacements(items);
+            return;
+        }
+
         // Collect all the changes we need to make
         let mut changes = Vec::new();
 
@@ -297,20 +788,126 @@ impl VisitMut for BarrelTransformVisitor {
                 items.get(index)
             {
                 if let Some(replacements) = self.import_replacements.remove(&import.span.lo.0) {
-                    // Remove the original import
-                    items.remove(index);
-
-                    // Insert all replacements at the position of the removed import
-                    let mut insert_pos = index;
-                    for import in replacements.into_iter() {
-                        items.insert(
-                            insert_pos,
-                            ModuleItem::ModuleDecl(swc_core::ecma::ast::ModuleDecl::Import(import)),
-                        );
-                        insert_pos += 1;
-                    }
+                    // Replace the original import with all replacements at its position
+                    items.splice(
+                        index..=index,
+                        replacements.into_iter().map(|import| {
+                            ModuleItem::ModuleDecl(swc_core::ecma::ast::ModuleDecl::Import(import))
+                        }),
+                    );
                 }
             }
         }
+
+        if self.merge_imports {
+            merge_imports(items, &self.source_dir);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_core::common::comments::{CommentKind, SingleThreadedComments};
+    use swc_core::common::DUMMY_SP;
+
+    fn comment(kind: CommentKind, text: &str) -> Comment {
+        Comment {
+            kind,
+            span: DUMMY_SP,
+            text: text.into(),
+        }
+    }
+
+    #[test]
+    fn test_bypassed_barrel_file() {
+        let dir = std::env::temp_dir().join(format!(
+            "swc-plugin-barrel-files-deep-import-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("src/modules/user/models")).unwrap();
+        std::fs::create_dir_all(dir.join("src/pages")).unwrap();
+        std::fs::write(
+            dir.join("src/modules/user/index.ts"),
+            "export { User } from './models/User';",
+        )
+        .unwrap();
+        std::fs::write(dir.join("src/modules/user/models/User.ts"), "").unwrap();
+        let cwd = dir.to_string_lossy().to_string();
+
+        let config: Config = serde_json::from_str(
+            r#"{
+                "aliases": [{ "pattern": "@modules/*", "paths": ["src/modules/*/index.ts"] }],
+                "patterns": ["src/modules/*/index.ts"],
+                "deep_import_mode": "error"
+            }"#,
+        )
+        .unwrap();
+        let visitor = |filename: &str| {
+            BarrelTransformVisitor::<SingleThreadedComments>::with_fs_root(
+                &config,
+                cwd.clone(),
+                &cwd,
+                filename.to_string(),
+                None,
+            )
+            .unwrap()
+            .unwrap()
+        };
+        let barrel_file = Some(format!("{}/src/modules/user/index.ts", cwd));
+
+        // Imports from outside of the module
+        let page = visitor("src/pages/home.ts");
+        assert_eq!(
+            page.bypassed_barrel_file("@modules/user/models/User"),
+            barrel_file
+        );
+        assert_eq!(
+            page.bypassed_barrel_file("../modules/user/models/User"),
+            barrel_file
+        );
+
+        // Imports of the barrel file itself and of other modules
+        assert_eq!(page.bypassed_barrel_file("@modules/user"), None);
+        assert_eq!(page.bypassed_barrel_file("../modules/user"), None);
+        assert_eq!(page.bypassed_barrel_file("../modules/user/index"), None);
+        assert_eq!(page.bypassed_barrel_file("./about"), None);
+        assert_eq!(page.bypassed_barrel_file("react/jsx-runtime"), None);
+
+        // Imports from inside of the module
+        let index = visitor("src/modules/user/models/index.ts");
+        assert_eq!(index.bypassed_barrel_file("./User"), None);
+        assert_eq!(
+            index.bypassed_barrel_file("@modules/user/models/User"),
+            None
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_is_pragma() {
+        assert!(is_pragma(
+            &comment(CommentKind::Line, " barrel-files-disable"),
+            DISABLE_FILE_PRAGMA
+        ));
+        assert!(is_pragma(
+            &comment(CommentKind::Block, " barrel-files-disable "),
+            DISABLE_FILE_PRAGMA
+        ));
+        assert!(is_pragma(
+            &comment(CommentKind::Block, "* barrel-files-disable-next-line "),
+            DISABLE_NEXT_LINE_PRAGMA
+        ));
+
+        assert!(!is_pragma(
+            &comment(CommentKind::Line, " barrel-files-disable-next-line"),
+            DISABLE_FILE_PRAGMA
+        ));
+        assert!(!is_pragma(
+            &comment(CommentKind::Line, " TODO: barrel-files-disable"),
+            DISABLE_FILE_PRAGMA
+        ));
     }
 }
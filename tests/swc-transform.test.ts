@@ -2,21 +2,44 @@ import process from "node:process";
 import path from "node:path";
 import fs from "node:fs/promises";
 import { spawn } from "node:child_process";
-import { describe, it, expect, afterEach } from "vitest";
+import { describe, it, expect, afterEach, beforeEach } from "vitest";
 
 const fixturesDir = path.resolve(__dirname, "fixtures");
 
 interface PluginConfig {
-    patterns: string[];
+    patterns: Array<
+        | string
+        | {
+              pattern: string;
+              unsupported_import_mode?: "error" | "warn" | "off";
+              invalid_barrel_mode?: "error" | "warn" | "off";
+              follow_nested?: boolean;
+              output?: "relative" | "alias" | "absolute-from-root";
+          }
+    >;
     aliases?: Array<{
         pattern: string;
         paths: string[];
         context?: string[];
     }>;
     symlinks?: Record<string, string>;
+    include?: string[];
+    exclude?: string[];
     debug?: boolean;
     unsupported_import_mode?: "error" | "warn" | "off";
     invalid_barrel_mode?: "error" | "warn" | "off";
+    missing_barrel_mode?: "error" | "warn" | "off";
+    verify_targets?: "off" | "exists" | "exports";
+    self_import_mode?: "error" | "warn" | "off";
+    deep_import_mode?: "error" | "warn" | "off";
+    output_path_style?: "relative" | "alias" | "absolute-from-root";
+    output_extension?: "preserve" | "strip" | "js" | "mjs" | Record<string, string>;
+    merge_imports?: boolean;
+    diagnostics_output?: "swc" | "console";
+    report?: string;
+    cache_dir?: string;
+    manifest?: string;
+    mode?: "transform" | "check";
 }
 
 interface CompilationOptions {
@@ -362,6 +385,30 @@ describe("SWC Barrel Files Transformation", () => {
         expect(result.stderr).toMatchInlineSnapshot(`""`);
     });
 
+    it("should transform named re-exports as the default export of barrel files", async () => {
+        await file(
+            "src/features/defaults/index.ts",
+            'export { Input as default, "icon-name" as Icon } from "./components/Input";',
+        );
+
+        const result = await transpileWithSwc({
+            filename: path.join(fixturesDir, "src/pages/test/defaults.ts"),
+            code: `
+                import Field, { Icon } from "#features/defaults";
+                console.log(Field, Icon);
+            `,
+            config: defaultConfig,
+        });
+
+        expect(result.code).toMatchInlineSnapshot(`
+          "import { Input as Field, "icon-name" as Icon } from "../../features/defaults/components/Input";
+          console.log(Field, Icon);
+          "
+        `);
+        expect(result.stdout).toMatchInlineSnapshot(`""`);
+        expect(result.stderr).toMatchInlineSnapshot(`""`);
+    });
+
     it("should show error for barrel files with default exports", async () => {
         await file("src/features/defaults/index.ts", `export default Button;`);
 
@@ -381,7 +428,8 @@ describe("SWC Barrel Files Transformation", () => {
              ,-[/cwd/tests/fixtures/src/pages/test/defaults.ts:2:1]
            1 | 
            2 |                 import Button from "#features/defaults";
-             :                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+             :                 ^^^^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^^^
+             :                                     \`-- unsupported construct at /cwd/tests/fixtures/src/features/defaults/index.ts:1:1
            3 |                 console.log(Button);
            4 |             
              \`----"
@@ -407,7 +455,8 @@ describe("SWC Barrel Files Transformation", () => {
              ,-[/cwd/tests/fixtures/src/pages/test/star-export.ts:2:1]
            1 | 
            2 |                 import { Button } from "#features/wildcard";
-             :                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+             :                 ^^^^^^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^^^^^
+             :                                       \`-- unsupported construct at /cwd/tests/fixtures/src/features/wildcard/index.ts:1:1
            3 |                 console.log(Button);
            4 |             
              \`----"
@@ -433,7 +482,8 @@ describe("SWC Barrel Files Transformation", () => {
              ,-[/cwd/tests/fixtures/src/pages/test/namespace.ts:2:1]
            1 | 
            2 |                 import { components } from "#features/namespace";
-             :                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+             :                 ^^^^^^^^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^^^^^^^^
+             :                                         \`-- unsupported construct at /cwd/tests/fixtures/src/features/namespace/index.ts:1:1
            3 |                 console.log(components);
            4 |             
              \`----"
@@ -459,7 +509,8 @@ describe("SWC Barrel Files Transformation", () => {
              ,-[/cwd/tests/fixtures/src/pages/test/namespace.ts:2:1]
            1 | 
            2 |                 import * as f1 from "#features/f1";
-             :                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+             :                 ^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^
+             :                                  \`-- unsupported construct at /cwd/tests/fixtures/src/features/with-code/index.ts:1:1
            3 |                 console.log(f1);
            4 |             
              \`----"
@@ -560,8 +611,8 @@ describe("SWC Barrel Files Transformation", () => {
         expect(result.code).toMatchInlineSnapshot(`""`);
         expect(result.stdout).toMatchInlineSnapshot(`""`);
         expect(result.stderr).toMatchInlineSnapshot(`
-          "thread '<unnamed>' panicked at src/lib.rs:44:61:
-          Error creating visitor: "E_INVALID_FILE_PATH: Absolute paths not starting with cwd are not supported: /non-existent-path/external/*/index.ts"
+          "thread '<unnamed>' panicked at src/lib.rs:68:33:
+          Error creating visitor: E_INVALID_FILE_PATH: Absolute paths not starting with cwd are not supported: /non-existent-path/external/*/index.ts
           note: run with \`RUST_BACKTRACE=1\` environment variable to display a backtrace
           plugin
 
@@ -621,6 +672,38 @@ describe("SWC Barrel Files Transformation", () => {
         `);
     });
 
+    it("should suggest similar exports for unresolved imports", async () => {
+        await file(
+            "src/features/typo/index.ts",
+            'export { Button } from "./components/Button";\nexport { Input } from "./components/Input";',
+        );
+
+        const result = await transpileWithSwc({
+            filename: path.join(fixturesDir, "src/pages/test/typo.ts"),
+            code: `
+                import { Buton } from "#features/typo";
+                console.log(Buton);
+            `,
+            config: defaultConfig,
+        });
+
+        expect(result.code).toMatchInlineSnapshot(`""`);
+        expect(result.stdout).toMatchInlineSnapshot(`""`);
+        expect(result.stderr).toMatchInlineSnapshot(`
+          "x Error processing barrel import: E_UNRESOLVED_EXPORTS: The following exports were not found in the barrel file /cwd/tests/fixtures/src/features/typo/index.ts: Buton
+             ,-[/cwd/tests/fixtures/src/pages/test/typo.ts:2:1]
+           1 | 
+           2 |                 import { Buton } from "#features/typo";
+             :                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+           3 |                 console.log(Buton);
+           4 |             
+             \`----
+
+          Advice: 
+            > Did you mean \`Button\` instead of \`Buton\`?"
+        `);
+    });
+
     it("should handle imports from barrel files with code", async () => {
         await file("src/features/with-code/index.ts", 'export const VERSION = "1.0.0";');
 
@@ -673,7 +756,8 @@ describe("SWC Barrel Files Transformation", () => {
              ,-[/cwd/tests/fixtures/src/pages/test/invalid.ts:2:1]
            1 | 
            2 |                 import { Button } from "#features/invalid";
-             :                 ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+             :                 ^^^^^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^^^^^
+             :                                      \`-- syntax error at /cwd/tests/fixtures/src/features/invalid/index.ts:4:23
            3 |                 console.log(Button);
            4 |             
              \`----"
@@ -822,6 +906,75 @@ describe("SWC Barrel Files Transformation", () => {
         expect(result2.stderr).toMatchInlineSnapshot(`""`);
     });
 
+    it("should prefer aliases with the longest literal prefix", async () => {
+        await file("src/features/button/index.ts", 'export { Button } from "./Button";');
+
+        const result = await transpileWithSwc({
+            filename: path.join(fixturesDir, "src/pages/test/precedence.ts"),
+            code: `
+                import { Button } from "@app-ui-button";
+                console.log(Button);
+            `,
+            config: {
+                ...defaultConfig,
+                aliases: [
+                    {
+                        pattern: "@app-*",
+                        paths: [path.join(fixturesDir, "src/legacy/*/index.ts")],
+                    },
+                    {
+                        pattern: "@app-ui-*",
+                        paths: [path.join(fixturesDir, "src/features/*/index.ts")],
+                    },
+                ],
+            },
+        });
+
+        expect(result.code).toMatchInlineSnapshot(`
+          "import { Button } from "../../features/button/Button";
+          console.log(Button);
+          "
+        `);
+        expect(result.stdout).toMatchInlineSnapshot(`""`);
+        expect(result.stderr).toMatchInlineSnapshot(`""`);
+    });
+
+    it("should warn about ambiguous aliases", async () => {
+        await file("src/features/user-ui/index.ts", 'export { UserCard } from "./UserCard";');
+
+        const result = await transpileWithSwc({
+            filename: path.join(fixturesDir, "src/pages/test/ambiguous.ts"),
+            code: `
+                import { UserCard } from "@app-user-ui";
+                console.log(UserCard);
+            `,
+            config: {
+                ...defaultConfig,
+                diagnostics_output: "console",
+                aliases: [
+                    {
+                        pattern: "@app-*",
+                        paths: [path.join(fixturesDir, "src/features/*/index.ts")],
+                    },
+                    {
+                        pattern: "@app-*-ui",
+                        paths: [path.join(fixturesDir, "src/features/*/ui.ts")],
+                    },
+                ],
+            },
+        });
+
+        expect(result.code).toMatchInlineSnapshot(`
+          "import { UserCard } from "../../features/user-ui/UserCard";
+          console.log(UserCard);
+          "
+        `);
+        expect(result.stdout).toMatchInlineSnapshot(`""`);
+        expect(result.stderr).toMatchInlineSnapshot(
+            `"Warning: E_AMBIGUOUS_ALIAS: Import "@app-user-ui" matches aliases "@app-*" and "@app-*-ui" with the same specificity, using "@app-*""`,
+        );
+    });
+
     it("should match barrel files without aliases", async () => {
         await file("src/features/some/index.ts", 'export { Button } from "./components/Button";');
 
@@ -1044,7 +1197,8 @@ describe("SWC Barrel Files Transformation", () => {
                  ,-[/cwd/tests/fixtures/src/pages/test/namespace.ts:2:1]
                1 | 
                2 |                     import * as f1 from "#features/f1";
-                 :                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+                 :                     ^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^
+                 :                                      \`-- unsupported construct at /cwd/tests/fixtures/src/features/invalid/index.ts:1:1
                3 |                     console.log(f1);
                4 |                 
                  \`----"
@@ -1091,6 +1245,7 @@ describe("SWC Barrel Files Transformation", () => {
                     `,
                 config: {
                     ...defaultConfig,
+                    diagnostics_output: "console",
                     unsupported_import_mode: "warn",
                 },
             });
@@ -1102,10 +1257,34 @@ describe("SWC Barrel Files Transformation", () => {
                 `);
             expect(result.stdout).toMatchInlineSnapshot(`""`);
             expect(result.stderr).toMatchInlineSnapshot(
-                `"Warning: Namespace imports are not supported for barrel file optimization. Import from #features/f1 will be skipped."`,
+                `"Warning: E_NO_NAMESPACE_IMPORTS: Namespace imports are not supported for barrel file optimization"`,
             );
         });
 
+        it("should not print warnings to the console by default", async () => {
+            await file("src/features/f1/index.ts", 'export { Button } from "./components/Button";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/namespace.ts"),
+                code: `
+                        import * as f1 from "#features/f1";
+                        console.log(f1);
+                    `,
+                config: {
+                    ...defaultConfig,
+                    unsupported_import_mode: "warn",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+                  "import * as f1 from "#features/f1";
+                  console.log(f1);
+                  "
+                `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).not.toContain("Warning: E_NO_NAMESPACE_IMPORTS");
+        });
+
         it("should ignore namespace imports when mode is 'off'", async () => {
             await file("src/features/f1/index.ts", 'export { Button } from "./components/Button";');
 
@@ -1174,7 +1353,7 @@ describe("SWC Barrel Files Transformation", () => {
             expect(result.code).toMatchInlineSnapshot(`""`);
             expect(result.stdout).toMatchInlineSnapshot(`""`);
             expect(result.stderr).toMatchInlineSnapshot(`
-              "thread '<unnamed>' panicked at src/lib.rs:41:6:
+              "thread '<unnamed>' panicked at src/lib.rs:65:6:
               E_INVALID_CONFIG: Error parsing barrel plugin configuration: Error("Invalid unsupported_import_mode 'invalid'. Valid options are: error, warn, off", line: 1, column: ?)
               note: run with \`RUST_BACKTRACE=1\` environment variable to display a backtrace
               plugin
@@ -1233,7 +1412,8 @@ describe("SWC Barrel Files Transformation", () => {
                  ,-[/cwd/tests/fixtures/src/pages/test/invalid.ts:2:1]
                1 | 
                2 |                     import { Button } from "#features/invalid";
-                 :                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+                 :                     ^^^^^^^^^^^^^^^^^^^^^|^^^^^^^^^^^^^^^^^^^^^
+                 :                                          \`-- unsupported construct at /cwd/tests/fixtures/src/features/invalid/index.ts:1:1
                3 |                     console.log(Button);
                4 |                 
                  \`----"
@@ -1251,6 +1431,7 @@ describe("SWC Barrel Files Transformation", () => {
                 `,
                 config: {
                     ...defaultConfig,
+                    diagnostics_output: "console",
                     invalid_barrel_mode: "warn",
                 },
             });
@@ -1290,6 +1471,36 @@ describe("SWC Barrel Files Transformation", () => {
             expect(result.stderr).toMatchInlineSnapshot(`""`);
         });
 
+        it("should warn on barrel files exporting a name twice when mode is 'warn'", async () => {
+            await file(
+                "src/features/duplicate/index.ts",
+                'export { Button } from "./components/Button";\nexport { IconButton as Button } from "./components/IconButton";',
+            );
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/duplicate.ts"),
+                code: `
+                    import { Button } from "#features/duplicate";
+                    console.log(Button);
+                `,
+                config: {
+                    ...defaultConfig,
+                    diagnostics_output: "console",
+                    invalid_barrel_mode: "warn",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { Button } from "#features/duplicate";
+              console.log(Button);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(
+                `"Warning: E_INVALID_BARREL_FILE: Invalid barrel file /cwd/tests/fixtures/src/features/duplicate/index.ts: Duplicate export in barrel file: 'Button' is exported from './components/Button' and './components/IconButton'"`,
+            );
+        });
+
         it("should handle barrel files with variable declarations when mode is 'warn'", async () => {
             await file("src/features/with-vars/index.ts", 'export const VERSION = "1.0.0";');
 
@@ -1301,6 +1512,7 @@ describe("SWC Barrel Files Transformation", () => {
                 `,
                 config: {
                     ...defaultConfig,
+                    diagnostics_output: "console",
                     invalid_barrel_mode: "warn",
                 },
             });
@@ -1358,7 +1570,7 @@ describe("SWC Barrel Files Transformation", () => {
             expect(result.code).toMatchInlineSnapshot(`""`);
             expect(result.stdout).toMatchInlineSnapshot(`""`);
             expect(result.stderr).toMatchInlineSnapshot(`
-              "thread '<unnamed>' panicked at src/lib.rs:41:6:
+              "thread '<unnamed>' panicked at src/lib.rs:65:6:
               E_INVALID_CONFIG: Error parsing barrel plugin configuration: Error("Invalid invalid_barrel_mode 'invalid'. Valid options are: error, warn, off", line: 1, column: ?)
               note: run with \`RUST_BACKTRACE=1\` environment variable to display a backtrace
               plugin
@@ -1368,6 +1580,1154 @@ describe("SWC Barrel Files Transformation", () => {
         });
     });
 
+    describe("missing_barrel_mode configuration", () => {
+        it("should fall through to less specific aliases when a barrel file is missing", async () => {
+            await file("src/features/ui/button/index.ts", 'export { Button } from "./Button";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/fallthrough.ts"),
+                code: `
+                    import { Button } from "@app/ui/button";
+                    console.log(Button);
+                `,
+                config: {
+                    ...defaultConfig,
+                    aliases: [
+                        {
+                            pattern: "@app/ui/*",
+                            paths: [path.join(fixturesDir, "src/ui/*/index.ts")],
+                        },
+                        {
+                            pattern: "@app/*/*",
+                            paths: [path.join(fixturesDir, "src/features/*/*/index.ts")],
+                        },
+                    ],
+                    patterns: [path.join(fixturesDir, "src/features/*/*/index.ts")],
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { Button } from "../../features/ui/button/Button";
+              console.log(Button);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should warn on missing barrel files when mode is 'warn'", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/missing.ts"),
+                code: `
+                    import logo from "#features/logo.svg";
+                    console.log(logo);
+                `,
+                config: {
+                    ...defaultConfig,
+                    diagnostics_output: "console",
+                    missing_barrel_mode: "warn",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import logo from "#features/logo.svg";
+              console.log(logo);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(
+                `"Warning: E_BARREL_FILE_NOT_FOUND: Could not resolve barrel file for import alias #features/logo.svg"`,
+            );
+        });
+
+        it("should error on missing local barrel files by default", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/missing-local.ts"),
+                code: `
+                    import { Button } from "../../features/missing/index.ts";
+                    console.log(Button);
+                `,
+                config: defaultConfig,
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`""`);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toContain(
+                "E_BARREL_FILE_NOT_FOUND: Could not find barrel file /cwd/tests/fixtures/src/features/missing/index.ts",
+            );
+        });
+
+        it("should skip missing local barrel files when mode is 'off'", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/missing-local.ts"),
+                code: `
+                    import { Button } from "../../features/missing/index.ts";
+                    console.log(Button);
+                `,
+                config: {
+                    ...defaultConfig,
+                    missing_barrel_mode: "off",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { Button } from "../../features/missing/index.ts";
+              console.log(Button);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should ignore missing barrel files when mode is 'off'", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/missing.ts"),
+                code: `
+                    import logo from "#features/logo.svg";
+                    console.log(logo);
+                `,
+                config: {
+                    ...defaultConfig,
+                    missing_barrel_mode: "off",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import logo from "#features/logo.svg";
+              console.log(logo);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("per-pattern options", () => {
+        it("should apply invalid_barrel_mode from the matched pattern", async () => {
+            await file("src/features/invalid/index.ts", 'export * from "./components/Button";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/invalid.ts"),
+                code: `
+                    import { Button } from "#features/invalid";
+                    console.log(Button);
+                `,
+                config: {
+                    ...defaultConfig,
+                    patterns: [
+                        {
+                            pattern: path.join(fixturesDir, "src/features/*/index.ts"),
+                            invalid_barrel_mode: "off",
+                        },
+                    ],
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { Button } from "#features/invalid";
+              console.log(Button);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should keep global modes for plain string patterns", async () => {
+            await file("src/features/f1/index.ts", 'export { Button } from "./components/Button";');
+            await file("src/features/f1/testing.ts", 'export { mock } from "./api/mocks";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/namespace.ts"),
+                code: `
+                    import * as f1 from "#features/f1";
+                    import * as testing from "#features/f1/testing";
+                    console.log(f1, testing);
+                `,
+                config: {
+                    ...defaultConfig,
+                    patterns: [
+                        path.join(fixturesDir, "src/features/*/index.ts"),
+                        {
+                            pattern: path.join(fixturesDir, "src/features/*/testing.ts"),
+                            unsupported_import_mode: "error",
+                        },
+                    ],
+                    unsupported_import_mode: "off",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`""`);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toContain(
+                "E_NO_NAMESPACE_IMPORTS: Namespace imports are not supported for barrel file optimization",
+            );
+            expect(result.stderr).toContain('import * as testing from "#features/f1/testing";');
+        });
+
+        it("should follow re-exports through nested barrel files", async () => {
+            await file(
+                "src/features/f1/index.ts",
+                `
+                    export { Button } from "./ui/index.ts";
+                    export { select } from "./model/selectors";
+                `,
+            );
+            await file("src/features/f1/ui/index.ts", 'export { Button } from "./Button";');
+            await file("src/features/f1/ui/Button.ts", "export const Button = 1;");
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/nested.ts"),
+                code: `
+                    import { Button, select } from "#features/f1";
+                    console.log(Button, select);
+                `,
+                config: {
+                    ...defaultConfig,
+                    patterns: [
+                        {
+                            pattern: path.join(fixturesDir, "src/features/*/index.ts"),
+                            follow_nested: true,
+                        },
+                        path.join(fixturesDir, "src/features/*/ui/index.ts"),
+                    ],
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { Button } from "../../features/f1/ui/Button";
+              import { select } from "../../features/f1/model/selectors";
+              console.log(Button, select);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("output_path_style configuration", () => {
+        it("should keep aliases when style is 'alias'", async () => {
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { User } from "./models/User";
+                    export { mock } from "./testing";
+                    export { Button } from "../../shared/ui/Button";
+                    export { Avatar } from "../../../lib/Avatar";
+                `,
+            );
+            await file("src/features/user/models/User.ts", "export const User = 1;");
+            await file("src/features/user/testing.ts", "export const mock = 1;");
+            await file("src/shared/ui/Button.tsx", "export const Button = 1;");
+            await file("lib/Avatar.tsx", "export const Avatar = 1;");
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/alias.ts"),
+                code: `
+                    import { User, mock, Button, Avatar } from "#features/user";
+                    console.log(User, mock, Button, Avatar);
+                `,
+                config: {
+                    ...defaultConfig,
+                    aliases: [
+                        ...(defaultConfig.aliases ?? []),
+                        {
+                            pattern: "@/*",
+                            paths: [path.join(fixturesDir, "src/*")],
+                        },
+                    ],
+                    output_path_style: "alias",
+                },
+            });
+
+            // `#features/user/models/User` would resolve to `src/features/user/models/User/index.ts`
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { User } from "@/features/user/models/User";
+              import { mock } from "#features/user/testing";
+              import { Button } from "@/shared/ui/Button";
+              import { Avatar } from "../../../lib/Avatar";
+              console.log(User, mock, Button, Avatar);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should use paths from the project root when style is 'absolute-from-root'", async () => {
+            await file("src/features/user/index.ts", 'export { User } from "./models/User";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/absolute.ts"),
+                code: `
+                    import { User } from "#features/user";
+                    console.log(User);
+                `,
+                config: {
+                    ...defaultConfig,
+                    output_path_style: "absolute-from-root",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { User } from "/tests/fixtures/src/features/user/models/User";
+              console.log(User);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should use the output path style of the pattern matching the barrel file", async () => {
+            await file("src/features/user/index.ts", 'export { User } from "./models/User";');
+            await file("src/features/user/testing.ts", 'export { mock } from "./mocks/user";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/output.ts"),
+                code: `
+                    import { User } from "#features/user";
+                    import { mock } from "#features/user/testing";
+                    console.log(User, mock);
+                `,
+                config: {
+                    ...defaultConfig,
+                    patterns: [
+                        {
+                            pattern: path.join(fixturesDir, "src/features/*/index.ts"),
+                            output: "absolute-from-root",
+                        },
+                        path.join(fixturesDir, "src/features/*/testing.ts"),
+                    ],
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { User } from "/tests/fixtures/src/features/user/models/User";
+              import { mock } from "../../features/user/mocks/user";
+              console.log(User, mock);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("output_extension configuration", () => {
+        beforeEach(async () => {
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { User } from "./models/User.ts";
+                    export { UserCard } from "./ui";
+                    export { select } from "./model/user.selectors";
+                `,
+            );
+            await file("src/features/user/ui/index.ts", 'export { UserCard } from "./UserCard";');
+        });
+
+        it("should keep extensions by default", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/preserve.ts"),
+                code: `
+                    import { User, UserCard, select } from "#features/user";
+                    console.log(User, UserCard, select);
+                `,
+                config: defaultConfig,
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { User } from "../../features/user/models/User.ts";
+              import { UserCard } from "../../features/user/ui";
+              import { select } from "../../features/user/model/user.selectors";
+              console.log(User, UserCard, select);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should strip script extensions when set to 'strip'", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/strip.ts"),
+                code: `
+                    import { User, UserCard } from "#features/user";
+                    console.log(User, UserCard);
+                `,
+                config: {
+                    ...defaultConfig,
+                    output_extension: "strip",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { User } from "../../features/user/models/User";
+              import { UserCard } from "../../features/user/ui";
+              console.log(User, UserCard);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should write ESM-compatible paths when set to 'js'", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/js.ts"),
+                code: `
+                    import { User, UserCard, select } from "#features/user";
+                    console.log(User, UserCard, select);
+                `,
+                config: {
+                    ...defaultConfig,
+                    output_extension: "js",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { User } from "../../features/user/models/User.js";
+              import { UserCard } from "../../features/user/ui/index.js";
+              import { select } from "../../features/user/model/user.selectors.js";
+              console.log(User, UserCard, select);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should rewrite extensions using a mapping table", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/mapping.ts"),
+                code: `
+                    import { User, select } from "#features/user";
+                    console.log(User, select);
+                `,
+                config: {
+                    ...defaultConfig,
+                    output_extension: { ".ts": ".mjs" },
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { User } from "../../features/user/models/User.mjs";
+              import { select } from "../../features/user/model/user.selectors";
+              console.log(User, select);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("merge_imports configuration", () => {
+        beforeEach(async () => {
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { UserCard } from "./ui/UserCard";
+                    export { UserAvatar } from "./ui/UserCard";
+                    export { selectUser } from "./model/selectors";
+                `,
+            );
+        });
+
+        it("should keep separate imports by default", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/features/user/page.ts"),
+                code: `
+                    import { UserCard } from "./ui/UserCard";
+                    import { UserAvatar, selectUser } from "#features/user";
+                    console.log(UserCard, UserAvatar, selectUser);
+                `,
+                config: {
+                    ...defaultConfig,
+                    self_import_mode: "off",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "./ui/UserCard";
+              import { UserAvatar } from "./ui/UserCard";
+              import { selectUser } from "./model/selectors";
+              console.log(UserCard, UserAvatar, selectUser);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should merge rewritten imports with existing imports of the same module", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/features/user/page.ts"),
+                code: `
+                    import { UserCard } from "./ui/UserCard";
+                    import { UserAvatar, selectUser } from "#features/user";
+                    import { UserCard as Card } from "#features/user";
+                    console.log(UserCard, UserAvatar, selectUser, Card);
+                `,
+                config: {
+                    ...defaultConfig,
+                    merge_imports: true,
+                    self_import_mode: "off",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserCard, UserAvatar, UserCard as Card } from "./ui/UserCard";
+              import { selectUser } from "./model/selectors";
+              console.log(UserCard, UserAvatar, selectUser, Card);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("include and exclude configuration", () => {
+        const code = `
+            import { UserCard } from "#features/user";
+            console.log(UserCard);
+        `;
+
+        beforeEach(async () => {
+            await file("src/features/user/index.ts", 'export { UserCard } from "./ui/UserCard";');
+        });
+
+        it("should leave files matched by exclude unchanged", async () => {
+            const config = {
+                ...defaultConfig,
+                exclude: ["**/*.stories.tsx", "tests/fixtures/src/legacy/**"],
+            };
+
+            const story = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/UserCard.stories.tsx"),
+                code,
+                config,
+            });
+            expect(story.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "#features/user";
+              console.log(UserCard);
+              "
+            `);
+            expect(story.stderr).toMatchInlineSnapshot(`""`);
+
+            const legacy = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/legacy/pages/home.ts"),
+                code,
+                config,
+            });
+            expect(legacy.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "#features/user";
+              console.log(UserCard);
+              "
+            `);
+            expect(legacy.stderr).toMatchInlineSnapshot(`""`);
+
+            const page = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/home.ts"),
+                code,
+                config,
+            });
+            expect(page.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "../../features/user/ui/UserCard";
+              console.log(UserCard);
+              "
+            `);
+            expect(page.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should only transform files matched by include", async () => {
+            const config = {
+                ...defaultConfig,
+                include: ["tests/fixtures/src/pages/**"],
+                debug: true,
+                diagnostics_output: "console" as const,
+            };
+
+            const page = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/home.ts"),
+                code,
+                config: { ...config, debug: false },
+            });
+            expect(page.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "../../features/user/ui/UserCard";
+              console.log(UserCard);
+              "
+            `);
+
+            const widget = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/widgets/header.ts"),
+                code,
+                config,
+            });
+            expect(widget.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "#features/user";
+              console.log(UserCard);
+              "
+            `);
+            expect(widget.stdout).toMatchInlineSnapshot(
+                `"[swc-plugin-barrel-files] Skipping /cwd/tests/fixtures/src/widgets/header.ts (reason: not matched by include)"`,
+            );
+        });
+    });
+
+    describe("pragma comments", () => {
+        beforeEach(async () => {
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { UserCard } from "./ui/UserCard";
+                    export { selectUser } from "./model/selectors";
+                `,
+            );
+        });
+
+        it("should skip the whole file with barrel-files-disable at the top", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/disabled.ts"),
+                code: `
+                    // barrel-files-disable
+                    import { UserCard } from "#features/user";
+                    import { selectUser } from "#features/user";
+                    console.log(UserCard, selectUser);
+                `,
+                config: defaultConfig,
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "// barrel-files-disable
+              import { UserCard } from "#features/user";
+              import { selectUser } from "#features/user";
+              console.log(UserCard, selectUser);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should skip an import with barrel-files-disable-next-line", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/disabled-line.ts"),
+                code: `
+                    import { UserCard } from "#features/user";
+                    // barrel-files-disable-next-line
+                    import { selectUser } from "#features/user";
+                    console.log(UserCard, selectUser);
+                `,
+                config: defaultConfig,
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "../../features/user/ui/UserCard";
+              // barrel-files-disable-next-line
+              import { selectUser } from "#features/user";
+              console.log(UserCard, selectUser);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("mode configuration", () => {
+        it("should report rewrites without changing the code in check mode", async () => {
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { UserCard } from "./ui/UserCard";
+                    export { selectUser } from "./model/selectors";
+                `,
+            );
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/check.ts"),
+                code: `
+                    import { UserCard, selectUser as select } from "#features/user";
+                    import { useState } from "react";
+                    console.log(UserCard, select, useState);
+                `,
+                config: {
+                    ...defaultConfig,
+                    mode: "check",
+                    diagnostics_output: "console",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserCard, selectUser as select } from "#features/user";
+              import { useState } from "react";
+              console.log(UserCard, select, useState);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(
+                `"Warning: Import from #features/user would be rewritten to: import { UserCard } from "../../features/user/ui/UserCard"; import { selectUser as select } from "../../features/user/model/selectors""`,
+            );
+        });
+    });
+
+    describe("cache_dir configuration", () => {
+        it("should persist barrel analyses and invalidate them when a barrel changes", async () => {
+            const config = {
+                ...defaultConfig,
+                cache_dir: "tests/fixtures/.cache",
+            };
+            const code = `
+                import { UserCard, selectUser } from "#features/user";
+                console.log(UserCard, selectUser);
+            `;
+
+            await file("src/features/user/index.ts", 'export { UserCard, selectUser } from "./ui/UserCard";');
+
+            const first = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/cached.ts"),
+                code,
+                config,
+            });
+            expect(first.code).toMatchInlineSnapshot(`
+              "import { UserCard, selectUser } from "../../features/user/ui/UserCard";
+              console.log(UserCard, selectUser);
+              "
+            `);
+            expect(await fs.readdir(path.join(fixturesDir, ".cache"))).toHaveLength(1);
+
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { UserCard } from "./ui/UserCard";
+                    export { selectUser } from "./model/selectors";
+                `,
+            );
+
+            const second = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/cached.ts"),
+                code,
+                config,
+            });
+            expect(second.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "../../features/user/ui/UserCard";
+              import { selectUser } from "../../features/user/model/selectors";
+              console.log(UserCard, selectUser);
+              "
+            `);
+            expect(second.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("manifest configuration", () => {
+        // Writes a manifest entry stamped with the current metadata of the barrel file
+        async function manifest(barrelFile: string, reExports: string[]) {
+            const stats = await fs.stat(path.join(fixturesDir, barrelFile), { bigint: true });
+            // The modification time in nanoseconds exceeds the safe integer range of numbers
+            const stamp = `{"size":${stats.size},"modified":${stats.mtimeNs},"content_hash":null}`;
+            const entries = reExports.map((name) => ({
+                exported_name: name,
+                source_path: `./ui/${name}`,
+                original_name: name,
+                is_default: false,
+                is_type_only: false,
+            }));
+
+            await file(
+                "manifest.json",
+                `{"tests/fixtures/${barrelFile}":{"stamp":${stamp},"re_exports":${JSON.stringify(entries)}}}`,
+            );
+        }
+
+        it("should use the re-exports from the manifest instead of parsing barrel files", async () => {
+            // Not a valid barrel file, so the transform fails if the file is parsed
+            await file("src/features/user/index.ts", 'export * from "./ui";');
+            await manifest("src/features/user/index.ts", ["UserCard"]);
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/manifest.ts"),
+                code: `
+                    import { UserCard } from "#features/user";
+                    console.log(UserCard);
+                `,
+                config: {
+                    ...defaultConfig,
+                    manifest: "tests/fixtures/manifest.json",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "../../features/user/ui/UserCard";
+              console.log(UserCard);
+              "
+            `);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+
+        it("should parse barrel files changed since the manifest was generated", async () => {
+            await file("src/features/user/index.ts", 'export { UserCard } from "./ui/UserCard";');
+            await manifest("src/features/user/index.ts", ["UserCard"]);
+            await file(
+                "src/features/user/index.ts",
+                'export { UserCard } from "./ui/UserCard";\nexport { UserAvatar } from "./ui/UserAvatar";',
+            );
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/manifest.ts"),
+                code: `
+                    import { UserAvatar } from "#features/user";
+                    console.log(UserAvatar);
+                `,
+                config: {
+                    ...defaultConfig,
+                    manifest: "tests/fixtures/manifest.json",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserAvatar } from "../../features/user/ui/UserAvatar";
+              console.log(UserAvatar);
+              "
+            `);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("self_import_mode configuration", () => {
+        beforeEach(async () => {
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { UserCard } from "./ui/UserCard";
+                    export { selectUser } from "./model/selectors";
+                `,
+            );
+        });
+
+        const code = `
+            import { selectUser } from "#features/user";
+            console.log(selectUser);
+        `;
+
+        it("should warn on files importing the barrel file of their own module and rewrite the import", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/features/user/ui/UserCard.ts"),
+                code,
+                config: {
+                    ...defaultConfig,
+                    diagnostics_output: "console",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { selectUser } from "../model/selectors";
+              console.log(selectUser);
+              "
+            `);
+            expect(result.stderr).toMatchInlineSnapshot(
+                `"Warning: E_SELF_IMPORT: /cwd/tests/fixtures/src/features/user/ui/UserCard.ts imports through the barrel file /cwd/tests/fixtures/src/features/user/index.ts of its own module, which creates a circular dependency"`,
+            );
+        });
+
+        it("should error on self-imports when mode is 'error'", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/features/user/ui/UserCard.ts"),
+                code,
+                config: {
+                    ...defaultConfig,
+                    self_import_mode: "error",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`
+              "x Error processing barrel import: E_SELF_IMPORT: /cwd/tests/fixtures/src/features/user/ui/UserCard.ts imports through the barrel file /cwd/tests/fixtures/src/features/user/index.ts of its own module, which creates a circular dependency
+                 ,-[/cwd/tests/fixtures/src/features/user/ui/UserCard.ts:2:1]
+               1 | 
+               2 |             import { selectUser } from "#features/user";
+                 :             ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+               3 |             console.log(selectUser);
+               4 |         
+                 \`----
+
+              Advice: 
+                > Modules inside the directory of a barrel file should import each other directly, as the barrel file may re-export the importing module"
+            `);
+        });
+
+        it("should not report imports of other barrel files", async () => {
+            await file("src/features/cart/index.ts", 'export { CartButton } from "./ui/CartButton";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/features/user/ui/UserCard.ts"),
+                code: `
+                    import { CartButton } from "#features/cart";
+                    console.log(CartButton);
+                `,
+                config: {
+                    ...defaultConfig,
+                    self_import_mode: "error",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { CartButton } from "../../cart/ui/CartButton";
+              console.log(CartButton);
+              "
+            `);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("deep_import_mode configuration", () => {
+        beforeEach(async () => {
+            await file("src/features/user/index.ts", 'export { UserCard } from "./ui/UserCard";');
+            await file("src/features/user/ui/UserCard.ts", "export const UserCard = 1;");
+            await file("src/features/user/model/selectors.ts", "export const selectUser = 1;");
+        });
+
+        it("should warn on imports bypassing a barrel file", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/deep.ts"),
+                code: `
+                    import { selectUser } from "../../features/user/model/selectors";
+                    console.log(selectUser);
+                `,
+                config: {
+                    ...defaultConfig,
+                    diagnostics_output: "console",
+                    deep_import_mode: "warn",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { selectUser } from "../../features/user/model/selectors";
+              console.log(selectUser);
+              "
+            `);
+            expect(result.stderr).toMatchInlineSnapshot(
+                `"Warning: E_DEEP_IMPORT: Import ../../features/user/model/selectors bypasses the barrel file /cwd/tests/fixtures/src/features/user/index.ts, which is the public API of its module"`,
+            );
+        });
+
+        it("should error on imports bypassing a barrel file when mode is 'error'", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/deep.ts"),
+                code: `
+                    import { selectUser } from "#features/user/model/selectors";
+                    console.log(selectUser);
+                `,
+                config: {
+                    ...defaultConfig,
+                    missing_barrel_mode: "off",
+                    deep_import_mode: "error",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`
+              "x Error processing barrel import: E_DEEP_IMPORT: Import #features/user/model/selectors bypasses the barrel file /cwd/tests/fixtures/src/features/user/index.ts, which is the public API of its module
+                 ,-[/cwd/tests/fixtures/src/pages/test/deep.ts:2:1]
+               1 | 
+               2 |                     import { selectUser } from "#features/user/model/selectors";
+                 :                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+               3 |                     console.log(selectUser);
+               4 |                 
+                 \`----
+
+              Advice: 
+                > Import from the barrel file instead, re-exporting the module from it if needed"
+            `);
+        });
+
+        it("should allow deep imports inside the module and barrel imports", async () => {
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/features/user/ui/UserCard.ts"),
+                code: `
+                    import { selectUser } from "../model/selectors";
+                    console.log(selectUser);
+                `,
+                config: {
+                    ...defaultConfig,
+                    deep_import_mode: "error",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { selectUser } from "../model/selectors";
+              console.log(selectUser);
+              "
+            `);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("verify_targets configuration", () => {
+        it("should report re-exports of missing modules at the import", async () => {
+            await file(
+                "src/features/user/index.ts",
+                `
+                    export { UserCard } from "./ui/UserCard";
+                    export { UserAvatar } from "./ui/UserAvatar";
+                `,
+            );
+            await file("src/features/user/ui/UserCard.tsx", "export const UserCard = () => <div />;");
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/verify.ts"),
+                code: `
+                    import { UserAvatar } from "#features/user";
+                    console.log(UserAvatar);
+                `,
+                config: {
+                    ...defaultConfig,
+                    verify_targets: "exists",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`
+              "x Error processing barrel import: E_INVALID_TARGET: Module /cwd/tests/fixtures/src/features/user/ui/UserAvatar, from which the barrel file /cwd/tests/fixtures/src/features/user/index.ts re-exports \`UserAvatar\`, does not exist
+                 ,-[/cwd/tests/fixtures/src/pages/test/verify.ts:2:1]
+               1 | 
+               2 |                     import { UserAvatar } from "#features/user";
+                 :                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+               3 |                     console.log(UserAvatar);
+               4 |                 
+                 \`----"
+            `);
+        });
+
+        it("should report re-exports of names the module does not export", async () => {
+            await file("src/features/user/index.ts", 'export { UserCard } from "./ui/UserCard";');
+            await file("src/features/user/ui/UserCard.tsx", "export const UserCards = () => <div />;");
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/verify.ts"),
+                code: `
+                    import { UserCard } from "#features/user";
+                    console.log(UserCard);
+                `,
+                config: {
+                    ...defaultConfig,
+                    verify_targets: "exports",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`
+              "x Error processing barrel import: E_INVALID_TARGET: Module /cwd/tests/fixtures/src/features/user/ui/UserCard.tsx does not export \`UserCard\`, re-exported as \`UserCard\` by the barrel file /cwd/tests/fixtures/src/features/user/index.ts
+                 ,-[/cwd/tests/fixtures/src/pages/test/verify.ts:2:1]
+               1 | 
+               2 |                     import { UserCard } from "#features/user";
+                 :                     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+               3 |                     console.log(UserCard);
+               4 |                 
+                 \`----
+
+              Advice: 
+                > /cwd/tests/fixtures/src/features/user/ui/UserCard.tsx exports \`UserCards\` instead"
+            `);
+        });
+
+        it("should transform imports of verified re-exports", async () => {
+            await file("src/features/user/index.ts", 'export { UserCard } from "./ui/UserCard.js";');
+            await file("src/features/user/ui/UserCard.tsx", "export const UserCard = () => <div />;");
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/verify.ts"),
+                code: `
+                    import { UserCard } from "#features/user";
+                    console.log(UserCard);
+                `,
+                config: {
+                    ...defaultConfig,
+                    verify_targets: "exports",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "../../features/user/ui/UserCard.js";
+              console.log(UserCard);
+              "
+            `);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+        });
+    });
+
+    describe("report configuration", () => {
+        async function readReport() {
+            const content = await fs.readFile(path.join(fixturesDir, "report.jsonl"), "utf-8");
+            return content
+                .trim()
+                .split("\n")
+                .map((line) => JSON.parse(line));
+        }
+
+        it("should append a record for each barrel import", async () => {
+            await file("src/features/user/index.ts", 'export { UserCard } from "./ui/UserCard";');
+            await file("src/features/invalid/index.ts", 'export * from "./ui/UserCard";');
+
+            const result = await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/report.ts"),
+                code: `
+                    import { UserCard } from "#features/user";
+                    import { UserAvatar } from "#features/invalid";
+                    import { useState } from "react";
+                    console.log(UserCard, UserAvatar, useState);
+                `,
+                config: {
+                    ...defaultConfig,
+                    invalid_barrel_mode: "off",
+                    report: "tests/fixtures/report.jsonl",
+                },
+            });
+
+            expect(result.code).toMatchInlineSnapshot(`
+              "import { UserCard } from "../../features/user/ui/UserCard";
+              import { UserAvatar } from "#features/invalid";
+              import { useState } from "react";
+              console.log(UserCard, UserAvatar, useState);
+              "
+            `);
+            expect(result.stdout).toMatchInlineSnapshot(`""`);
+            expect(result.stderr).toMatchInlineSnapshot(`""`);
+
+            await transpileWithSwc({
+                filename: path.join(fixturesDir, "src/pages/test/report-error.ts"),
+                code: `
+                    import { Action } from "#features/user";
+                    console.log(Action);
+                `,
+                config: {
+                    ...defaultConfig,
+                    report: "tests/fixtures/report.jsonl",
+                },
+            });
+
+            expect(await readReport()).toEqual([
+                {
+                    file: "tests/fixtures/src/pages/test/report.ts",
+                    specifier: "#features/user",
+                    barrel_file: "tests/fixtures/src/features/user/index.ts",
+                    status: "rewritten",
+                    imports: ["../../features/user/ui/UserCard"],
+                    reason: null,
+                    code: null,
+                },
+                {
+                    file: "tests/fixtures/src/pages/test/report.ts",
+                    specifier: "#features/invalid",
+                    barrel_file: "tests/fixtures/src/features/invalid/index.ts",
+                    status: "skipped",
+                    imports: [],
+                    reason: "E_INVALID_BARREL_FILE: Invalid barrel file /cwd/tests/fixtures/src/features/invalid/index.ts: Wildcard exports are not supported in barrel files: Wildcard exports are not allowed in barrel files",
+                    code: "E_INVALID_BARREL_FILE",
+                },
+                {
+                    file: "tests/fixtures/src/pages/test/report-error.ts",
+                    specifier: "#features/user",
+                    barrel_file: "tests/fixtures/src/features/user/index.ts",
+                    status: "error",
+                    imports: [],
+                    reason: "E_UNRESOLVED_EXPORTS: The following exports were not found in the barrel file /cwd/tests/fixtures/src/features/user/index.ts: Action",
+                    code: "E_UNRESOLVED_EXPORTS",
+                },
+            ]);
+        });
+    });
+
     describe("symlinks configuration", () => {
         it("should transform imports from external paths using symlinks", async () => {
             await file("src/ui/index.ts", 'export { Button } from "./Button";\nexport { Input } from "./Input";');
@@ -16,14 +16,45 @@ export interface AliasConfig {
     context?: string[];
 }
 
+/**
+ * Configuration for a barrel file pattern with options applied only to the matched barrel files.
+ */
+export interface PatternConfig {
+    /**
+     * The path to barrel files relative to the current working directory.
+     */
+    pattern: string;
+    /**
+     * How to handle unsupported import patterns for the matched barrel files.
+     * Overrides the global `unsupported_import_mode`.
+     */
+    unsupported_import_mode?: "error" | "warn" | "off";
+    /**
+     * How to handle the matched barrel files if they are invalid.
+     * Overrides the global `invalid_barrel_mode`.
+     */
+    invalid_barrel_mode?: "error" | "warn" | "off";
+    /**
+     * Follow re-exports through nested barrel files down to the source module.
+     * @default false
+     */
+    follow_nested?: boolean;
+    /**
+     * Style of the import paths pointing to the source modules of the matched barrel files.
+     * Overrides the global `output_path_style`.
+     */
+    output?: "relative" | "alias" | "absolute-from-root";
+}
+
 /**
  * Configuration for the plugin.
  */
 export interface PluginConfig {
     /**
      * An array of paths to barrel files relative to the current working directory.
+     * Each entry can be either a path or a pattern configuration with per-pattern options.
      */
-    patterns: string[];
+    patterns: Array<string | PatternConfig>;
     /**
      * An optional array of alias configurations.
      */
@@ -40,10 +71,33 @@ export interface PluginConfig {
      */
     symlinks?: Record<string, string>;
     /**
-     * Enables debug logging to stdout.
+     * Globs relative to the current working directory for the source files to transform.
+     * All files are transformed if not set. `**` matches any number of directories.
+     * @example ["src/**"]
+     */
+    include?: string[];
+    /**
+     * Globs relative to the current working directory for the source files to leave unchanged.
+     * @example ["**\/*.stories.tsx", "src/legacy/**"]
+     */
+    exclude?: string[];
+    /**
+     * Enables debug logging.
      * @default false
      */
     debug?: boolean;
+    /**
+     * Whether to rewrite barrel imports, or to only report the imports that would be rewritten
+     * as warnings and leave the code unchanged.
+     * @default "transform"
+     */
+    mode?: "transform" | "check";
+    /**
+     * Where to report warnings and debug logs: as SWC diagnostics at the import that triggered them,
+     * or printed to stderr (warnings) and stdout (debug logs).
+     * @default "swc"
+     */
+    diagnostics_output?: "swc" | "console";
     /**
      * How to handle unsupported import patterns (e.g. namespace imports).
      * @default "error"
@@ -54,4 +108,63 @@ export interface PluginConfig {
      * @default "error"
      */
     invalid_barrel_mode?: "error" | "warn" | "off";
+    /**
+     * How to handle imports without an existing barrel file: aliased imports for which no matching alias
+     * resolves to an existing file and relative imports matched by `patterns` that point to a missing file.
+     * @default "error"
+     */
+    missing_barrel_mode?: "error" | "warn" | "off";
+    /**
+     * How to verify the modules targeted by re-exports before rewriting imports to them: not at all,
+     * check that the module exists, or also parse it to check that it exports the re-exported name.
+     * @default "off"
+     */
+    verify_targets?: "off" | "exists" | "exports";
+    /**
+     * How to report files importing through the barrel file of their own module, e.g. `src/modules/user/ui/Card.ts`
+     * importing `@modules/user`, which creates a circular dependency. The import is rewritten in any case.
+     * @default "warn"
+     */
+    self_import_mode?: "error" | "warn" | "off";
+    /**
+     * How to report deep imports bypassing a barrel file, e.g. `@modules/user/models/User` imported from
+     * outside of `src/modules/user`, to enforce barrel files as the public API of their modules.
+     * @default "off"
+     */
+    deep_import_mode?: "error" | "warn" | "off";
+    /**
+     * Style of the import paths pointing to the source modules: relative to the importing file,
+     * mapped back through `aliases` (falling back to relative paths), or from the current working directory.
+     * @default "relative"
+     */
+    output_path_style?: "relative" | "alias" | "absolute-from-root";
+    /**
+     * How to rewrite script extensions of the import paths pointing to the source modules.
+     * A mapping table replaces the listed extensions only, the empty key stands for paths without an extension.
+     * @default "preserve"
+     * @example { ".ts": ".js", "": ".js" }
+     */
+    output_extension?: "preserve" | "strip" | "js" | "mjs" | Record<string, string>;
+    /**
+     * Merges rewritten imports with other imports of the same module.
+     * @default false
+     */
+    merge_imports?: boolean;
+    /**
+     * Path to a JSON Lines file, relative to the current working directory, to which a record
+     * of every barrel import (rewritten, skipped or failed) is appended.
+     */
+    report?: string;
+    /**
+     * Directory, relative to the current working directory, in which the analyses of barrel
+     * files are persisted between builds. Entries are invalidated when a barrel file changes.
+     * @example "node_modules/.cache/swc-plugin-barrel-files"
+     */
+    cache_dir?: string;
+    /**
+     * Path to a JSON file, relative to the current working directory, with the precomputed
+     * re-exports of barrel files. Barrel files listed in the manifest are not parsed.
+     * @example "barrel-manifest.json"
+     */
+    manifest?: string;
 }